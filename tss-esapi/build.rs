@@ -7,7 +7,14 @@ fn main() {
     println!("cargo:rustc-check-cfg=cfg(has_tss_base_rc_values_28_to_51)");
     println!("cargo:rustc-check-cfg=cfg(has_tss_base_rc_values_52_to_53)");
     println!("cargo:rustc-check-cfg=cfg(has_tpmu_sensitive_create)");
+    println!("cargo:rustc-check-cfg=cfg(has_esys_trsess_get_auth_required)");
     println!("cargo:rustc-check-cfg=cfg(has_esys_tr_get_tpm_handle)");
+    println!("cargo:rustc-check-cfg=cfg(has_tpmu_attest_nv_digest)");
+    println!("cargo:rustc-check-cfg=cfg(has_esys_ac_commands)");
+    println!("cargo:rustc-check-cfg=cfg(has_esys_mac)");
+    println!("cargo:rustc-check-cfg=cfg(has_esys_certify_x509)");
+    println!("cargo:rustc-check-cfg=cfg(has_esys_act)");
+    println!("cargo:rustc-check-cfg=cfg(has_tpms_derive)");
 
     let tss_version_string = std::env::var("DEP_TSS2_ESYS_VERSION")
         .expect("Failed to parse ENV variable DEP_TSS2_ESYS_VERSION as string");
@@ -24,6 +31,14 @@ fn main() {
         tss_version
     );
 
+    // The bundled bindings of the x86_64-unknown-darwin target were generated
+    // from an older version of the TSS and lack some of the newer symbols.
+    #[cfg(feature = "generate-bindings")]
+    let has_recent_bindings = true;
+    #[cfg(not(feature = "generate-bindings"))]
+    let has_recent_bindings =
+        std::env::var("CARGO_CFG_TARGET_OS").map_or(true, |target_os| target_os != "macos");
+
     let hierarchy_is_esys_tr_req = VersionReq::parse(">=3.0.0").unwrap();
     if hierarchy_is_esys_tr_req.matches(&tss_version) {
         println!("cargo:rustc-cfg=hierarchy_is_esys_tr")
@@ -44,11 +59,51 @@ fn main() {
         println!("cargo:rustc-cfg=has_tpmu_sensitive_create")
     }
 
+    let has_esys_trsess_get_auth_required_req = VersionReq::parse(">=3.0.0").unwrap();
+    if has_esys_trsess_get_auth_required_req.matches(&tss_version) {
+        println!("cargo:rustc-cfg=has_esys_trsess_get_auth_required")
+    }
+
+    let has_esys_certify_x509_req = VersionReq::parse(">=3.0.0").unwrap();
+    if has_recent_bindings && has_esys_certify_x509_req.matches(&tss_version) {
+        println!("cargo:rustc-cfg=has_esys_certify_x509")
+    }
+
+    // The bundled bindings contain TPMS_NV_DIGEST_CERTIFY_INFO but not the
+    // nvDigest member of TPMU_ATTEST, which the crate does not rely on.
+    let has_tpmu_attest_nv_digest_req = VersionReq::parse(">=4.0.0").unwrap();
+    if has_recent_bindings && has_tpmu_attest_nv_digest_req.matches(&tss_version) {
+        println!("cargo:rustc-cfg=has_tpmu_attest_nv_digest")
+    }
+
+    let has_esys_act_req = VersionReq::parse(">=3.0.0").unwrap();
+    if has_recent_bindings && has_esys_act_req.matches(&tss_version) {
+        println!("cargo:rustc-cfg=has_esys_act")
+    }
+
     #[cfg(feature = "generate-bindings")]
     {
         let has_esys_tr_get_tpm_handle_req = VersionReq::parse(">=2.4.0").unwrap();
         if has_esys_tr_get_tpm_handle_req.matches(&tss_version) {
             println!("cargo:rustc-cfg=has_esys_tr_get_tpm_handle")
         }
+
+        // The attached component and MAC functions are missing from the bundled
+        // bindings, which were generated from the 3.2.2 version of the tpm2-tss.
+        let has_esys_ac_commands_req = VersionReq::parse(">=4.0.0").unwrap();
+        if has_esys_ac_commands_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_esys_ac_commands")
+        }
+
+        let has_esys_mac_req = VersionReq::parse(">=4.0.0").unwrap();
+        if has_esys_mac_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_esys_mac")
+        }
+
+        // TPMS_DERIVE is missing from the bundled bindings as well.
+        let has_tpms_derive_req = VersionReq::parse(">=4.0.0").unwrap();
+        if has_tpms_derive_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_tpms_derive")
+        }
     }
 }
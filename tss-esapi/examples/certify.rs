@@ -369,6 +369,7 @@ fn main() {
                 Default::default(),
                 Default::default(),
                 None,
+                false,
             )
         })
         .unwrap();
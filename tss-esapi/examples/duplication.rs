@@ -56,6 +56,11 @@
  *
  * This is chosen such that the ObjectAttributes of the Storage Key can enforce that
  * encrypted duplication must occur.
+ *
+ * Finally, the duplicated storage key is rewrapped on TPM 2, so that it is protected by
+ * a third primary key instead of Primary 2. This is what a duplication authority, such
+ * as a key escrow service, does to re-parent a duplicated object without the key ever
+ * being exposed. Only the outer wrapper is replaced, the inner wrapper is left as it is.
  */
 
 use tss_esapi::{
@@ -104,8 +109,8 @@ fn main() {
     .expect("Failed to create Context");
 
     // On both contexts, create their primary key.
-    let primary_key_1 = create_primary_key(&mut context_1);
-    let primary_key_2 = create_primary_key(&mut context_2);
+    let primary_key_1 = create_primary_key(&mut context_1, Hierarchy::Owner);
+    let primary_key_2 = create_primary_key(&mut context_2, Hierarchy::Owner);
 
     // Get the new_parent_name. This is needed to satisfy the policy to allow duplication.
     let (_, target_parent_name, _) = context_2
@@ -339,7 +344,7 @@ fn main() {
 
             ctx.policy_duplication_select(
                 policy_session,
-                object_to_duplicate_name,
+                object_to_duplicate_name.clone(),
                 target_parent_name,
                 false,
             )
@@ -391,10 +396,10 @@ fn main() {
         .execute_with_nullauth_session(|ctx| {
             ctx.import(
                 primary_key_2_key_handle,
-                Some(data),
+                Some(data.clone()),
                 public.clone(),
-                duplicate,
-                secret,
+                duplicate.clone(),
+                secret.clone(),
                 SymmetricDefinitionObject::AES_128_CFB,
             )
         })
@@ -406,14 +411,14 @@ fn main() {
             ctx.load(
                 primary_key_2_key_handle.into(),
                 private_storage_key_2,
-                public,
+                public.clone(),
             )
         })
         .unwrap();
 
     // 🎉 Hooray, duplication worked!
 
-    // Unload the primary key.
+    // Unload the primary key, it is recreated later on when rewrapping.
     context_2.flush_context(primary_key_2_key_handle).unwrap();
 
     // And now descendants of the storage key can be loaded and used too, even though we didn't
@@ -438,9 +443,90 @@ fn main() {
     println!("hmac2 = {:?}", hmac2);
     // They are the same!
     assert_eq!(hmac1, hmac2);
+
+    // We're done with the storage key on TPM 2, unload it to save space.
+    context_2
+        .flush_context(loaded_storage_key_2.into())
+        .unwrap();
+
+    // ---------------------------------------------------------------------
+    // Now rewrap the duplicated storage key so that it is protected by a third primary key.
+
+    // Primary keys are derived from the hierarchy seed and the template, so primary key 2
+    // can be recreated. The third primary key is created in the endorsement hierarchy,
+    // which makes it a different key even though the same template is used.
+    let primary_key_2 = create_primary_key(&mut context_2, Hierarchy::Owner);
+    let primary_key_3 = create_primary_key(&mut context_2, Hierarchy::Endorsement);
+
+    // Rewrap requires authorization for the old parent (primary key 2), while only the
+    // public area of the new parent (primary key 3) needs to be loaded.
+    let (rewrapped_duplicate, rewrapped_secret) = context_2
+        .execute_with_nullauth_session(|ctx| {
+            ctx.rewrap(
+                primary_key_2.key_handle.into(),
+                primary_key_3.key_handle.into(),
+                duplicate,
+                object_to_duplicate_name,
+                secret,
+            )
+        })
+        .map_err(|err| {
+            eprintln!("⚠️  {}", err);
+            err
+        })
+        .unwrap();
+
+    context_2
+        .flush_context(primary_key_2.key_handle.into())
+        .unwrap();
+
+    // The inner wrapper was not changed, so the same encryption key and symmetric
+    // algorithm are used when importing the rewrapped storage key.
+    let private_storage_key_3 = context_2
+        .execute_with_nullauth_session(|ctx| {
+            ctx.import(
+                primary_key_3.key_handle.into(),
+                Some(data),
+                public.clone(),
+                rewrapped_duplicate,
+                rewrapped_secret,
+                SymmetricDefinitionObject::AES_128_CFB,
+            )
+        })
+        .unwrap();
+
+    let loaded_storage_key_3 = context_2
+        .execute_with_nullauth_session(|ctx| {
+            ctx.load(primary_key_3.key_handle, private_storage_key_3, public)
+        })
+        .unwrap();
+
+    context_2
+        .flush_context(primary_key_3.key_handle.into())
+        .unwrap();
+
+    // The child keys can be used under the rewrapped storage key as well.
+    let hmac3 = context_2
+        .execute_with_nullauth_session(|ctx| {
+            let loaded_hmackey = ctx
+                .load(
+                    loaded_storage_key_3,
+                    hmac_key.out_private.clone(),
+                    hmac_key.out_public.clone(),
+                )
+                .unwrap();
+
+            ctx.execute_with_temporary_object(loaded_hmackey.into(), |ctx, handle| {
+                ctx.hmac(handle, input_data.clone(), HashingAlgorithm::Sha256)
+            })
+        })
+        .unwrap();
+
+    println!("hmac3 = {:?}", hmac3);
+    assert_eq!(hmac1, hmac3);
 }
 
-fn create_primary_key(context: &mut Context) -> CreatePrimaryKeyResult {
+fn create_primary_key(context: &mut Context, hierarchy: Hierarchy) -> CreatePrimaryKeyResult {
     context
         .execute_with_nullauth_session(|ctx| {
             let object_attributes = ObjectAttributesBuilder::new()
@@ -471,7 +557,7 @@ fn create_primary_key(context: &mut Context) -> CreatePrimaryKeyResult {
                 .build()
                 .expect("public to be valid");
 
-            ctx.create_primary(Hierarchy::Owner, public, None, None, None, None)
+            ctx.create_primary(hierarchy, public, None, None, None, None)
         })
         .unwrap()
 }
@@ -144,6 +144,7 @@ pub fn load_ak(
                     Default::default(),
                     Default::default(),
                     None,
+                    false,
                 )
             })?;
 
@@ -209,6 +210,7 @@ pub fn create_ak<IKC: IntoKeyCustomization>(
                     Default::default(),
                     Default::default(),
                     None,
+                    false,
                 )
             })?;
 
@@ -0,0 +1,78 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    constants::{tss::TPMA_PERMANENT_INLOCKOUT, PropertyTag},
+    Context, Error, Result, WrapperErrorKind,
+};
+use log::error;
+
+/// The state of the dictionary attack protection of the TPM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DictionaryAttackStatus {
+    lockout_counter: u32,
+    max_auth_fail: u32,
+    lockout_interval: u32,
+    lockout_recovery: u32,
+    in_lockout: bool,
+}
+
+impl DictionaryAttackStatus {
+    /// Returns the current number of authorization failures.
+    pub const fn lockout_counter(&self) -> u32 {
+        self.lockout_counter
+    }
+
+    /// Returns the number of authorization failures before the TPM
+    /// enters the lockout mode.
+    pub const fn max_auth_fail(&self) -> u32 {
+        self.max_auth_fail
+    }
+
+    /// Returns the time in seconds after which one authorization
+    /// failure is forgotten.
+    pub const fn lockout_interval(&self) -> u32 {
+        self.lockout_interval
+    }
+
+    /// Returns the time in seconds after a failed authorization using
+    /// the lockout authorization before it can be used again.
+    pub const fn lockout_recovery(&self) -> u32 {
+        self.lockout_recovery
+    }
+
+    /// Returns whether the TPM is in the lockout mode.
+    pub const fn in_lockout(&self) -> bool {
+        self.in_lockout
+    }
+
+    /// Returns the number of authorization failures that are left
+    /// before the TPM enters the lockout mode.
+    pub const fn remaining_tries(&self) -> u32 {
+        self.max_auth_fail.saturating_sub(self.lockout_counter)
+    }
+}
+
+/// Retrieves the state of the dictionary attack protection of the TPM.
+///
+/// # Details
+/// The values are read using [Context::get_tpm_property].
+///
+/// # Errors
+/// * if the TPM does not report one of the properties a `WrongValueFromTpm`
+///   wrapper error is returned.
+pub fn get_status(context: &mut Context) -> Result<DictionaryAttackStatus> {
+    let mut get_property = |property: PropertyTag| {
+        context.get_tpm_property(property)?.ok_or_else(|| {
+            error!("The TPM did not report the {:?} property", property);
+            Error::local_error(WrapperErrorKind::WrongValueFromTpm)
+        })
+    };
+    Ok(DictionaryAttackStatus {
+        lockout_counter: get_property(PropertyTag::LockoutCounter)?,
+        max_auth_fail: get_property(PropertyTag::MaxAuthFail)?,
+        lockout_interval: get_property(PropertyTag::LockoutInterval)?,
+        lockout_recovery: get_property(PropertyTag::LockoutRecovery)?,
+        in_lockout: get_property(PropertyTag::Permanent)? & TPMA_PERMANENT_INLOCKOUT != 0,
+    })
+}
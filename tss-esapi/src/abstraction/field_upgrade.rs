@@ -0,0 +1,80 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    structures::{HashAgile, MaxBuffer},
+    Context, Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::io::{ErrorKind, Read};
+
+/// Streams field upgrade data from a reader to the TPM.
+///
+/// # Arguments
+/// * `context` - The [Context] used to communicate with the TPM.
+/// * `reader` - The reader providing the vendor specific upgrade data.
+///
+/// # Details
+/// The field upgrade needs to have been started using
+/// [Context::field_upgrade_start]. The data is read in blocks of
+/// [MaxBuffer::MAX_SIZE] bytes which are provided to the TPM using
+/// [Context::field_upgrade_data], until either the reader is exhausted
+/// or the TPM does not expect any more data.
+///
+/// # Returns
+/// The digests returned by the TPM for the last block that was provided,
+/// i.e. the digest of the next block, which is `None` when the TPM does
+/// not expect any more data, and the digest of the first block.
+///
+/// # Errors
+/// * if the reader does not provide any data or if reading from it fails,
+///   an `InvalidParam` wrapper error is returned.
+/// * if the TPM does not expect any more data while the reader has not
+///   been exhausted, an `InvalidParam` wrapper error is returned.
+pub fn field_upgrade_data<R: Read>(
+    context: &mut Context,
+    reader: &mut R,
+) -> Result<(Option<HashAgile>, HashAgile)> {
+    let mut block = vec![0u8; MaxBuffer::MAX_SIZE];
+    let mut digests = None;
+    loop {
+        let size = read_block(reader, &mut block)?;
+        if size == 0 {
+            break;
+        }
+        let (next_digest, first_digest) =
+            context.field_upgrade_data(MaxBuffer::from_bytes(&block[..size])?)?;
+        let done = next_digest.is_none();
+        digests = Some((next_digest, first_digest));
+        if done {
+            if read_block(reader, &mut block[..1])? != 0 {
+                error!("The TPM does not expect the remaining field upgrade data");
+                return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+            }
+            break;
+        }
+    }
+    digests.ok_or_else(|| {
+        error!("No field upgrade data was provided");
+        Error::local_error(WrapperErrorKind::InvalidParam)
+    })
+}
+
+/// Fills the block with data from the reader, returning the number of
+/// bytes read. This is smaller than the block only when the reader has
+/// been exhausted.
+fn read_block<R: Read>(reader: &mut R, block: &mut [u8]) -> Result<usize> {
+    let mut size = 0;
+    while size < block.len() {
+        match reader.read(&mut block[size..]) {
+            Ok(0) => break,
+            Ok(read) => size += read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => {
+                error!("Failed to read field upgrade data: {}", e);
+                return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+            }
+        }
+    }
+    Ok(size)
+}
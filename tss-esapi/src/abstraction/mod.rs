@@ -3,11 +3,17 @@
 
 pub mod ak;
 pub mod cipher;
+pub mod dictionary_attack;
 pub mod ek;
+pub mod field_upgrade;
 pub mod nv;
 pub mod pcr;
 pub mod public;
+pub mod self_test;
+pub mod sequence;
 pub mod transient;
+#[cfg(has_esys_certify_x509)]
+pub mod x509;
 
 use std::convert::TryFrom;
 
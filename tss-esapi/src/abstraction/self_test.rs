@@ -0,0 +1,71 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    constants::{return_code::TpmFormatZeroWarning, tss::TPM2_RC_TESTING},
+    error::{TpmFormatZeroResponseCode, TpmResponseCode},
+    structures::AlgorithmList,
+    Context, Error, Result, ReturnCode,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The time to wait between two attempts at completing the tests.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Runs the incremental self test of the TPM until all the
+/// `required` algorithms have been tested.
+///
+/// # Details
+/// The algorithms are passed to [Context::incremental_self_test] until none
+/// of them remain in the list of algorithms that the TPM still has to test.
+/// While the TPM is performing the tests in the background the calls are
+/// repeated after a short pause.
+///
+/// # Errors
+/// * if the tests have not been completed within `timeout` a TSS error with
+///   a `Testing` [TpmFormatZeroWarning] is returned.
+/// * if one of the tests fails the TPM enters failure mode and the error
+///   returned by the TPM is passed on.
+pub fn test_algorithms(
+    context: &mut Context,
+    required: AlgorithmList,
+    timeout: Duration,
+) -> Result<()> {
+    let start = Instant::now();
+    let mut to_test = required;
+    loop {
+        match context.incremental_self_test(to_test.clone()) {
+            Ok(to_do_list) => {
+                let mut remaining = to_test.into_inner();
+                remaining.retain(|algorithm| to_do_list.contains(algorithm));
+                to_test = AlgorithmList::try_from(remaining)?;
+            }
+            Err(error) if is_testing(&error) => {}
+            Err(error) => return Err(error),
+        }
+        if to_test.is_empty() {
+            return Ok(());
+        }
+        if start.elapsed() >= timeout {
+            error!(
+                "The self test of the algorithms {:?} did not complete",
+                to_test
+            );
+            return Err(Error::tss_error(ReturnCode::try_from(TPM2_RC_TESTING)?));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Checks whether the error indicates that the TPM is performing self tests.
+fn is_testing(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+            TpmFormatZeroResponseCode::Warning(warning)
+        ))) if warning.error_number() == TpmFormatZeroWarning::Testing
+    )
+}
@@ -0,0 +1,149 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::convert::TryFrom;
+
+use crate::{
+    constants::PropertyTag,
+    handles::{ObjectHandle, PcrHandle, SequenceHandle},
+    interface_types::{
+        algorithm::HashingAlgorithm, reserved_handles::Hierarchy, session_handles::AuthSession,
+    },
+    structures::{Auth, Digest, DigestValues, HashcheckTicket, MaxBuffer},
+    Context, Error, Result, WrapperErrorKind,
+};
+
+/// Hash, HMAC or event sequence
+///
+/// Provides a [`std::io::Write`] implementation on top of a TPM sequence object, splitting
+/// the data written into chunks that fit the TPM input buffer.
+///
+/// Hash and event sequences are started without any sessions. The remaining sequence commands
+/// (as well as starting an HMAC sequence) require authorization, so the sessions that are set
+/// on the context when the sequence is used need to include one that is able to authorize them
+/// (e.g. a password session).
+///
+/// NOTE: When the `Sequence` is dropped without being completed, the sequence object is flushed.
+#[derive(Debug)]
+pub struct Sequence<'a> {
+    context: &'a mut Context,
+    sequence_handle: Option<SequenceHandle>,
+    buffer_size: usize,
+}
+
+impl<'a> Sequence<'a> {
+    /// Starts a hash sequence using the specified hashing algorithm.
+    pub fn hash(
+        context: &'a mut Context,
+        hashing_algorithm: HashingAlgorithm,
+        auth: Option<Auth>,
+    ) -> Result<Self> {
+        let sequence_handle = context
+            .execute_without_session(|ctx| ctx.hash_sequence_start(hashing_algorithm, auth))?;
+        Sequence::new(context, sequence_handle)
+    }
+
+    /// Starts an HMAC sequence using the specified key and hashing algorithm.
+    pub fn hmac(
+        context: &'a mut Context,
+        handle: ObjectHandle,
+        auth: Option<Auth>,
+        hashing_algorithm: HashingAlgorithm,
+    ) -> Result<Self> {
+        let sequence_handle = context.hmac_start(handle, auth, hashing_algorithm)?;
+        Sequence::new(context, sequence_handle)
+    }
+
+    /// Starts an event sequence.
+    pub fn event(context: &'a mut Context, auth: Option<Auth>) -> Result<Self> {
+        let sequence_handle = context
+            .execute_without_session(|ctx| ctx.hash_sequence_start(HashingAlgorithm::Null, auth))?;
+        Sequence::new(context, sequence_handle)
+    }
+
+    /// Completes a hash or HMAC sequence and returns the result.
+    pub fn complete(mut self, hierarchy: Hierarchy) -> Result<(Digest, HashcheckTicket)> {
+        let sequence_handle = self.take_sequence_handle()?;
+        self.context
+            .sequence_complete(sequence_handle, MaxBuffer::default(), hierarchy)
+    }
+
+    /// Completes an event sequence, optionally extending the PCR, and returns the
+    /// digests for all the implemented hashing algorithms.
+    ///
+    /// The PCR is authorized using a password session with an empty auth value
+    /// and the sequence is authorized using the first session set on the context.
+    pub fn complete_event(mut self, pcr_handle: Option<PcrHandle>) -> Result<DigestValues> {
+        let sequence_handle = self.take_sequence_handle()?;
+        let (sequence_session, _, _) = self.context.sessions();
+        self.context.execute_with_sessions(
+            (Some(AuthSession::Password), sequence_session, None),
+            |ctx| ctx.event_sequence_complete(pcr_handle, sequence_handle, MaxBuffer::default()),
+        )
+    }
+
+    fn new(context: &'a mut Context, sequence_handle: SequenceHandle) -> Result<Self> {
+        let buffer_size = match max_input_buffer_size(context) {
+            Ok(buffer_size) => buffer_size,
+            Err(e) => {
+                let _ = context.flush_context(sequence_handle.into());
+                return Err(e);
+            }
+        };
+        Ok(Sequence {
+            context,
+            sequence_handle: Some(sequence_handle),
+            buffer_size,
+        })
+    }
+
+    fn take_sequence_handle(&mut self) -> Result<SequenceHandle> {
+        self.sequence_handle.take().ok_or_else(|| {
+            log::error!("The sequence has already been completed");
+            Error::local_error(WrapperErrorKind::InvalidHandleState)
+        })
+    }
+}
+
+/// Get the maximum size of the data that can be sent to the TPM in a single sequence update.
+fn max_input_buffer_size(context: &mut Context) -> Result<usize> {
+    Ok(context
+        .get_tpm_property(PropertyTag::InputBuffer)?
+        .map(usize::try_from)
+        .transpose()
+        .map_err(|_| {
+            log::error!("Failed to obtain valid maximum input buffer size");
+            Error::local_error(WrapperErrorKind::InternalError)
+        })?
+        .map_or(MaxBuffer::MAX_SIZE, |size| {
+            std::cmp::min(size, MaxBuffer::MAX_SIZE)
+        }))
+}
+
+impl std::io::Write for Sequence<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let sequence_handle = self
+            .sequence_handle
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::Other, "Sequence completed"))?;
+        let size = std::cmp::min(self.buffer_size, buf.len());
+        let data = MaxBuffer::from_bytes(&buf[0..size])
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        self.context
+            .sequence_update(sequence_handle, data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        Ok(size)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        // Data isn't buffered
+        Ok(())
+    }
+}
+
+impl Drop for Sequence<'_> {
+    fn drop(&mut self) {
+        if let Some(sequence_handle) = self.sequence_handle.take() {
+            let _ = self.context.flush_context(sequence_handle.into());
+        }
+    }
+}
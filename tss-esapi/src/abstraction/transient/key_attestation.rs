@@ -150,6 +150,7 @@ impl TransientKeyContext {
             Default::default(),
             Default::default(),
             None,
+            false,
         );
         Ok((
             ek::create_ek_object(
@@ -0,0 +1,297 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    handles::{KeyHandle, ObjectHandle},
+    structures::{MaxBuffer, Signature, SignatureScheme},
+    Context, Error, Result, WrapperErrorKind,
+};
+use log::error;
+use picky_asn1_der::Asn1DerError;
+use picky_asn1_x509::{Extensions, Name, Validity};
+use std::{convert::TryFrom, mem::size_of};
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_BIT_STRING: u8 = 0x03;
+const TAG_SEQUENCE: u8 = 0x30;
+// The extensions are an explicitly tagged ([3]) field of the TBSCertificate.
+const TAG_EXTENSIONS: u8 = 0xa3;
+
+/// The parts of an X.509 certificate that are provided to the TPM
+/// when a certificate is created using [certify_x509].
+///
+/// # Details
+/// The remaining fields of the certificate (version, serial number,
+/// signature algorithm and subject public key info) are added by the TPM.
+#[derive(Debug, Clone)]
+pub struct PartialCertificate {
+    issuer: Name,
+    validity: Validity,
+    subject: Name,
+    extensions: Extensions,
+}
+
+impl PartialCertificate {
+    /// Creates a new partial certificate.
+    ///
+    /// # Details
+    /// The `extensions` need to be consistent with the attributes of the
+    /// key that is being certified (e.g. a key usage of `digitalSignature`
+    /// requires a signing key), otherwise the TPM will reject them.
+    pub fn new(issuer: Name, validity: Validity, subject: Name, extensions: Extensions) -> Self {
+        PartialCertificate {
+            issuer,
+            validity,
+            subject,
+            extensions,
+        }
+    }
+
+    /// Returns the issuer.
+    pub fn issuer(&self) -> &Name {
+        &self.issuer
+    }
+
+    /// Returns the validity.
+    pub fn validity(&self) -> &Validity {
+        &self.validity
+    }
+
+    /// Returns the subject.
+    pub fn subject(&self) -> &Name {
+        &self.subject
+    }
+
+    /// Returns the extensions.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// Encodes the partial certificate in the format expected by
+    /// [Context::certify_x509].
+    pub fn to_der(&self) -> Result<Vec<u8>> {
+        Ok(der_encode(TAG_SEQUENCE, &self.encoded_fields()?.concat()))
+    }
+
+    /// DER encodes the issuer, validity, subject and extensions fields.
+    fn encoded_fields(&self) -> Result<[Vec<u8>; 4]> {
+        Ok([
+            picky_asn1_der::to_vec(&self.issuer).map_err(encoding_error("issuer"))?,
+            picky_asn1_der::to_vec(&self.validity).map_err(encoding_error("validity"))?,
+            picky_asn1_der::to_vec(&self.subject).map_err(encoding_error("subject"))?,
+            der_encode(
+                TAG_EXTENSIONS,
+                &picky_asn1_der::to_vec(&self.extensions).map_err(encoding_error("extensions"))?,
+            ),
+        ])
+    }
+}
+
+/// Creates a DER encoded X.509 certificate for a key, signed by the TPM.
+///
+/// # Arguments
+/// * `context` - The [Context] used to communicate with the TPM.
+/// * `key_handle` - The [KeyHandle] of the key for which the certificate is created.
+/// * `signing_key_handle` - The [KeyHandle] of the key used to sign the certificate.
+/// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+/// * `partial_certificate` - The [PartialCertificate] holding the issuer, validity, subject
+///                           and extensions of the certificate.
+///
+/// # Details
+/// The certificate is assembled from the partial certificate and the fields that
+/// the TPM added to it when it was signed using [Context::certify_x509].
+///
+/// Both `key_handle` and `signing_key_handle` need to be authorized, so the sessions
+/// that are set on the context need to include two sessions that are able to
+/// authorize them (e.g. password sessions).
+///
+/// # Errors
+/// * if the signing key does not produce an RSA or ECDSA signature an `UnsupportedParam`
+///   wrapper error will be returned.
+pub fn certify_x509(
+    context: &mut Context,
+    key_handle: KeyHandle,
+    signing_key_handle: KeyHandle,
+    signing_scheme: SignatureScheme,
+    partial_certificate: &PartialCertificate,
+) -> Result<Vec<u8>> {
+    let [issuer, validity, subject, extensions] = partial_certificate.encoded_fields()?;
+    let (added_to_certificate, _, signature) = context.certify_x509(
+        ObjectHandle::from(key_handle),
+        signing_key_handle,
+        signing_scheme,
+        MaxBuffer::try_from(der_encode(
+            TAG_SEQUENCE,
+            &[&issuer[..], &validity, &subject, &extensions].concat(),
+        ))?,
+    )?;
+
+    // The TPM adds the version, serial number, signature algorithm and
+    // the subject public key info to the partial certificate.
+    let added_fields = der_elements(der_content(added_to_certificate.as_bytes(), TAG_SEQUENCE)?)?;
+    let (version, serial_number, signature_algorithm, subject_public_key_info) =
+        match added_fields.as_slice() {
+            [version, serial_number, signature_algorithm, subject_public_key_info] => (
+                *version,
+                *serial_number,
+                *signature_algorithm,
+                *subject_public_key_info,
+            ),
+            _ => {
+                error!(
+                    "Unexpected number of fields added to the certificate: {}",
+                    added_fields.len()
+                );
+                return Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm));
+            }
+        };
+
+    let tbs_certificate = der_encode(
+        TAG_SEQUENCE,
+        &[
+            version,
+            serial_number,
+            signature_algorithm,
+            &issuer,
+            &validity,
+            &subject,
+            subject_public_key_info,
+            &extensions,
+        ]
+        .concat(),
+    );
+
+    let signature_value = der_encode(
+        TAG_BIT_STRING,
+        &[&[0x00][..], &signature_value(signature)?].concat(),
+    );
+
+    Ok(der_encode(
+        TAG_SEQUENCE,
+        &[&tbs_certificate[..], signature_algorithm, &signature_value].concat(),
+    ))
+}
+
+/// Converts a signature into the value used in the certificate.
+fn signature_value(signature: Signature) -> Result<Vec<u8>> {
+    match signature {
+        Signature::RsaSsa(rsa_signature) | Signature::RsaPss(rsa_signature) => {
+            Ok(rsa_signature.signature().as_bytes().to_vec())
+        }
+        Signature::EcDsa(ecc_signature) => Ok(der_encode(
+            TAG_SEQUENCE,
+            &[
+                der_encode_unsigned_integer(ecc_signature.signature_r().as_bytes()),
+                der_encode_unsigned_integer(ecc_signature.signature_s().as_bytes()),
+            ]
+            .concat(),
+        )),
+        _ => {
+            error!(
+                "Signature algorithm {:?} is not supported in certificates",
+                signature.algorithm()
+            );
+            Err(Error::local_error(WrapperErrorKind::UnsupportedParam))
+        }
+    }
+}
+
+fn encoding_error(field: &'static str) -> impl FnOnce(Asn1DerError) -> Error {
+    move |e| {
+        error!(
+            "Failed to DER encode the {} of the certificate: {}",
+            field, e
+        );
+        Error::local_error(WrapperErrorKind::InvalidParam)
+    }
+}
+
+/// Encodes a DER element with a single byte tag.
+fn der_encode(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![tag];
+    let length = content.len();
+    if length < 0x80 {
+        encoded.push(length as u8);
+    } else {
+        let length_bytes = length.to_be_bytes();
+        let first_non_zero = length_bytes
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or(length_bytes.len() - 1);
+        encoded.push(0x80 | (length_bytes.len() - first_non_zero) as u8);
+        encoded.extend_from_slice(&length_bytes[first_non_zero..]);
+    }
+    encoded.extend_from_slice(content);
+    encoded
+}
+
+/// Encodes big endian bytes as a non negative DER integer.
+fn der_encode_unsigned_integer(bytes: &[u8]) -> Vec<u8> {
+    let first_non_zero = bytes
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(bytes.len());
+    let value = &bytes[first_non_zero..];
+    // A leading zero is needed when the value is zero or
+    // when the most significant bit is set.
+    let content = match value.first() {
+        Some(byte) if byte & 0x80 == 0 => value.to_vec(),
+        _ => [&[0x00][..], value].concat(),
+    };
+    der_encode(TAG_INTEGER, &content)
+}
+
+/// Returns the size of the header and the size of the content
+/// of the DER element at the start of `data`.
+fn der_header(data: &[u8]) -> Result<(usize, usize)> {
+    match data {
+        [_, length, ..] if *length < 0x80 => Ok((2, usize::from(*length))),
+        [_, length, rest @ ..] => {
+            let count = usize::from(length & 0x7f);
+            if count == 0 || count > size_of::<usize>() || count > rest.len() {
+                error!("Invalid length in DER encoded data");
+                return Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm));
+            }
+            let content_length = rest[..count]
+                .iter()
+                .fold(0, |length, byte| (length << 8) | usize::from(*byte));
+            Ok((2 + count, content_length))
+        }
+        _ => {
+            error!("DER encoded data is too short");
+            Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm))
+        }
+    }
+}
+
+/// Returns the content of a DER element, ensuring that it has the expected tag.
+fn der_content(element: &[u8], tag: u8) -> Result<&[u8]> {
+    let (header_length, content_length) = der_header(element)?;
+    if element[0] != tag || header_length.checked_add(content_length) != Some(element.len()) {
+        error!(
+            "DER encoded data is not a valid element with tag {:#04X}",
+            tag
+        );
+        return Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm));
+    }
+    Ok(&element[header_length..])
+}
+
+/// Splits DER encoded data into its elements.
+fn der_elements(mut data: &[u8]) -> Result<Vec<&[u8]>> {
+    let mut elements = Vec::new();
+    while !data.is_empty() {
+        let (header_length, content_length) = der_header(data)?;
+        let element_length = header_length
+            .checked_add(content_length)
+            .filter(|element_length| *element_length <= data.len())
+            .ok_or_else(|| {
+                error!("DER encoded element exceeds the available data");
+                Error::local_error(WrapperErrorKind::WrongValueFromTpm)
+            })?;
+        let (element, rest) = data.split_at(element_length);
+        elements.push(element);
+        data = rest;
+    }
+    Ok(elements)
+}
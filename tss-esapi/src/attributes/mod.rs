@@ -21,6 +21,14 @@ pub mod algorithm;
 
 pub mod command_code;
 
+/// Representation of the attributes defined in the
+/// Attribute structures -> TPMA_ACT section of
+/// the specification.
+#[cfg(has_esys_act)]
+pub mod act;
+
+#[cfg(has_esys_act)]
+pub use act::ActAttributes;
 pub use algorithm::AlgorithmAttributes;
 pub use command_code::CommandCodeAttributes;
 pub use locality::{LocalityAttributes, LocalityAttributesBuilder};
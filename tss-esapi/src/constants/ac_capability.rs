@@ -0,0 +1,87 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::tss::{TPM2_AT_ANY, TPM2_AT_ERROR, TPM2_AT_PV1, TPM2_AT_VEND},
+    tss2_esys::TPM_AT,
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::convert::TryFrom;
+
+/// Enum representing the different attached component capability tags.
+///
+/// # Details
+/// This corresponds to TPM_AT.
+///
+/// The tags starting from `TPM2_AT_VEND` are vendor specific, so
+/// the `Vendor` variant holds the [VendorAcCapability] tag.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AcCapability {
+    Any,
+    Error,
+    Pv1,
+    Vendor(VendorAcCapability),
+}
+
+/// Type holding a vendor specific attached component capability tag.
+///
+/// # Details
+/// The value is guaranteed to be greater than or equal to `TPM2_AT_VEND`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VendorAcCapability(TPM_AT);
+
+impl VendorAcCapability {
+    /// Returns the value of the tag.
+    pub const fn value(&self) -> TPM_AT {
+        self.0
+    }
+}
+
+impl From<VendorAcCapability> for TPM_AT {
+    fn from(vendor_ac_capability: VendorAcCapability) -> Self {
+        vendor_ac_capability.0
+    }
+}
+
+impl TryFrom<TPM_AT> for VendorAcCapability {
+    type Error = Error;
+
+    fn try_from(tpm_at: TPM_AT) -> Result<Self> {
+        if tpm_at < TPM2_AT_VEND {
+            error!(
+                "value = {} is not a vendor specific attached component capability.",
+                tpm_at
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        Ok(VendorAcCapability(tpm_at))
+    }
+}
+
+impl From<AcCapability> for TPM_AT {
+    fn from(ac_capability: AcCapability) -> Self {
+        match ac_capability {
+            AcCapability::Any => TPM2_AT_ANY,
+            AcCapability::Error => TPM2_AT_ERROR,
+            AcCapability::Pv1 => TPM2_AT_PV1,
+            AcCapability::Vendor(vendor_ac_capability) => vendor_ac_capability.into(),
+        }
+    }
+}
+
+impl TryFrom<TPM_AT> for AcCapability {
+    type Error = Error;
+
+    fn try_from(tpm_at: TPM_AT) -> Result<Self> {
+        match tpm_at {
+            TPM2_AT_ANY => Ok(AcCapability::Any),
+            TPM2_AT_ERROR => Ok(AcCapability::Error),
+            TPM2_AT_PV1 => Ok(AcCapability::Pv1),
+            tag if tag >= TPM2_AT_VEND => Ok(AcCapability::Vendor(VendorAcCapability(tag))),
+            _ => {
+                error!("value = {} did not match any AcCapability.", tpm_at);
+                Err(Error::local_error(WrapperErrorKind::InvalidParam))
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::tss::{
+        TPM2_EO_BITCLEAR, TPM2_EO_BITSET, TPM2_EO_EQ, TPM2_EO_NEQ, TPM2_EO_SIGNED_GE,
+        TPM2_EO_SIGNED_GT, TPM2_EO_SIGNED_LE, TPM2_EO_SIGNED_LT, TPM2_EO_UNSIGNED_GE,
+        TPM2_EO_UNSIGNED_GT, TPM2_EO_UNSIGNED_LE, TPM2_EO_UNSIGNED_LT,
+    },
+    tss2_esys::TPM2_EO,
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, ToPrimitive};
+use std::convert::TryFrom;
+
+/// Enum representing the different arithmetic and logical
+/// comparison operations (operand A is the value held by
+/// the TPM and operand B is the value provided by the caller).
+#[derive(FromPrimitive, ToPrimitive, Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u16)]
+pub enum ArithmeticComparison {
+    /// A = B
+    Equal = TPM2_EO_EQ,
+    /// A != B
+    NotEqual = TPM2_EO_NEQ,
+    /// A > B (signed)
+    SignedGreaterThan = TPM2_EO_SIGNED_GT,
+    /// A > B (unsigned)
+    UnsignedGreaterThan = TPM2_EO_UNSIGNED_GT,
+    /// A < B (signed)
+    SignedLessThan = TPM2_EO_SIGNED_LT,
+    /// A < B (unsigned)
+    UnsignedLessThan = TPM2_EO_UNSIGNED_LT,
+    /// A >= B (signed)
+    SignedGreaterOrEqual = TPM2_EO_SIGNED_GE,
+    /// A >= B (unsigned)
+    UnsignedGreaterOrEqual = TPM2_EO_UNSIGNED_GE,
+    /// A <= B (signed)
+    SignedLessOrEqual = TPM2_EO_SIGNED_LE,
+    /// A <= B (unsigned)
+    UnsignedLessOrEqual = TPM2_EO_UNSIGNED_LE,
+    /// All bits set in B are set in A
+    BitSet = TPM2_EO_BITSET,
+    /// All bits set in B are clear in A
+    BitClear = TPM2_EO_BITCLEAR,
+}
+
+impl From<ArithmeticComparison> for TPM2_EO {
+    fn from(arithmetic_comparison: ArithmeticComparison) -> TPM2_EO {
+        // The values are well defined so this cannot fail.
+        arithmetic_comparison.to_u16().unwrap()
+    }
+}
+
+impl TryFrom<TPM2_EO> for ArithmeticComparison {
+    type Error = Error;
+    fn try_from(tpm_arithmetic_comparison: TPM2_EO) -> Result<ArithmeticComparison> {
+        ArithmeticComparison::from_u16(tpm_arithmetic_comparison).ok_or_else(|| {
+            error!(
+                "value = {} did not match any ArithmeticComparison.",
+                tpm_arithmetic_comparison
+            );
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })
+    }
+}
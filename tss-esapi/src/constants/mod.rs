@@ -59,7 +59,22 @@ pub mod command_code;
 /// Constants -> TPM_PT_PCR section of the specification.
 pub mod pcr_property_tag;
 
+/// Representation of the constants defined in
+/// Constants -> TPM_EO section of the specification.
+pub mod arithmetic_comparison;
+
+/// Representation of the constants defined in
+/// Constants -> TPM_CLOCK_ADJUST section of the specification.
+pub mod clock_adjust;
+
+/// Representation of the constants defined in
+/// Constants -> TPM_AT section of the specification.
+pub mod ac_capability;
+
+pub use ac_capability::{AcCapability, VendorAcCapability};
+pub use arithmetic_comparison::ArithmeticComparison;
 pub use capabilities::CapabilityType;
+pub use clock_adjust::ClockAdjust;
 pub use command_code::CommandCode;
 pub use ecc::EccCurveIdentifier;
 pub use nv_index_type::NvIndexType;
@@ -355,6 +355,11 @@ pub const TPM2_SE_HMAC: TPM2_SE = 0x00;
 pub const TPM2_SE_POLICY: TPM2_SE = 0x01;
 pub const TPM2_SE_TRIAL: TPM2_SE = 0x03; /* The policy session is being used to compute the policyHash and not for command authorization.This setting modifies some policy commands and prevents session from being used to authorize a command. */
 
+pub const TPM2_AT_ANY: TPM_AT = 0x00000000; /* in a command, a non-specific request for AC information; in a response, indicates that outputData is not meaningful */
+pub const TPM2_AT_ERROR: TPM_AT = 0x00000001; /* indicates a TCG defined, device-specific error */
+pub const TPM2_AT_PV1: TPM_AT = 0x00000002; /* indicates the most significant 32 bits of a pairing value for the AC */
+pub const TPM2_AT_VEND: TPM_AT = 0x80000000; /* value added to a TPM2_AT to indicate a vendor-specific tag value */
+
 pub const TPM2_CAP_FIRST: TPM2_CAP = 0x00000000;
 pub const TPM2_CAP_ALGS: TPM2_CAP = 0x00000000; /* TPM2_ALG_ID1 */
 pub const TPM2_CAP_HANDLES: TPM2_CAP = 0x00000001; /* TPM2_HANDLE */
@@ -3,10 +3,11 @@
 mod handle_manager;
 use crate::{
     attributes::SessionAttributesBuilder,
-    constants::{CapabilityType, PropertyTag, SessionType},
+    constants::{tss::TPM2_PT_VAR, CapabilityType, PropertyTag, SessionType},
     handles::{ObjectHandle, SessionHandle},
     interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
     structures::{CapabilityData, SymmetricDefinition},
+    tcti::{CustomTctiContext, Tcti},
     tcti_ldr::{TabrmdConfig, TctiContext, TctiNameConf},
     tss2_esys::*,
     Error, Result, ReturnCode, WrapperErrorKind as ErrorKind,
@@ -56,7 +57,7 @@ pub struct Context {
     ),
     /// TCTI context handle associated with the ESYS context.
     /// As with the ESYS context, an optional Mbox wrapper allows the context to be deallocated.
-    _tcti_context: TctiContext,
+    _tcti_context: ContextTcti,
     /// Handle manager that keep tracks of the state of the handles and how they are to be
     /// disposed.
     handle_manager: HandleManager,
@@ -64,6 +65,25 @@ pub struct Context {
     cached_tpm_properties: HashMap<PropertyTag, u32>,
 }
 
+/// The TCTI context used by a [Context].
+#[derive(Debug)]
+enum ContextTcti {
+    /// TCTI context created via the TCTI Loader Library.
+    Loaded(TctiContext),
+    /// TCTI context backed by a [Tcti] implemented in Rust.
+    Custom(CustomTctiContext),
+}
+
+impl ContextTcti {
+    /// Get access to the inner C pointer
+    fn tcti_context_ptr(&mut self) -> *mut TSS2_TCTI_CONTEXT {
+        match self {
+            ContextTcti::Loaded(tcti_context) => tcti_context.tcti_context_ptr(),
+            ContextTcti::Custom(tcti_context) => tcti_context.tcti_context_ptr(),
+        }
+    }
+}
+
 // Implementation of the TPM commands
 mod tpm_commands;
 // Implementation of the ESAPI session administration
@@ -71,6 +91,11 @@ mod tpm_commands;
 mod session_administration;
 // Implementation of the general ESAPI ESYS_TR functions
 mod general_esys_tr;
+// Implementation of the asynchronous context
+#[cfg(feature = "async")]
+mod async_context;
+#[cfg(feature = "async")]
+pub use async_context::AsyncContext;
 
 impl Context {
     /// Create a new ESYS context based on the desired TCTI
@@ -89,9 +114,29 @@ impl Context {
     /// * if either `Tss2_TctiLdr_Initiialize` or `Esys_Initialize` fail, a corresponding
     ///   Tss2ResponseCode will be returned
     pub fn new(tcti_name_conf: TctiNameConf) -> Result<Self> {
-        let mut esys_context = null_mut();
+        let tcti_context = TctiContext::initialize(tcti_name_conf)?;
+        Context::initialize(ContextTcti::Loaded(tcti_context))
+    }
+
+    /// Create a new ESYS context that uses a TCTI implemented in Rust.
+    ///
+    /// # Details
+    /// The commands of the context are transmitted to the TPM through the
+    /// provided [Tcti], which allows the TPM to be accessed over channels that
+    /// are not supported by the TCTI modules of the TSS. The TCTI is dropped
+    /// together with the context.
+    ///
+    /// The same threading considerations as for [Context::new] apply.
+    ///
+    /// # Errors
+    /// * if `Esys_Initialize` fails, a corresponding Tss2ResponseCode will be returned
+    pub fn new_with_tcti<T: Tcti + 'static>(tcti: T) -> Result<Self> {
+        Context::initialize(ContextTcti::Custom(CustomTctiContext::new(tcti)))
+    }
 
-        let mut _tcti_context = TctiContext::initialize(tcti_name_conf)?;
+    /// Create a new ESYS context using the TCTI context.
+    fn initialize(mut _tcti_context: ContextTcti) -> Result<Self> {
+        let mut esys_context = null_mut();
 
         ReturnCode::ensure_success(
             unsafe {
@@ -339,6 +384,10 @@ impl Context {
     /// the TPM has a value for it else None will be returned.
     /// If None is returned then use default from specification.
     ///
+    /// Only the values of the fixed properties are cached, the values
+    /// of the variable properties (e.g. [PropertyTag::LockoutCounter])
+    /// are always retrieved from the TPM.
+    ///
     /// # Errors
     /// If the TPM returns a value that is wrong when
     /// its capabilities is being retrieved then a
@@ -374,17 +423,19 @@ impl Context {
             _ => return Err(Error::WrapperError(ErrorKind::WrongValueFromTpm)),
         };
 
+        let mut value = None;
         for tagged_property in props {
-            // If we are returned a property we don't know, just ignore it
-            let _ = self
-                .cached_tpm_properties
-                .insert(tagged_property.property(), tagged_property.value());
-        }
-
-        if let Some(val) = self.cached_tpm_properties.get(&property) {
-            return Ok(Some(*val));
+            if tagged_property.property() == property {
+                value = Some(tagged_property.value());
+            }
+            // The variable properties can change so only the fixed ones are cached.
+            if TPM2_PT::from(tagged_property.property()) < TPM2_PT_VAR {
+                let _ = self
+                    .cached_tpm_properties
+                    .insert(tagged_property.property(), tagged_property.value());
+            }
         }
-        Ok(None)
+        Ok(value)
     }
 
     // ////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,304 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod integrity_collection_pcr;
+mod non_volatile_storage;
+mod object_commands;
+mod signing_and_signature_verification;
+mod waiter;
+
+use crate::{
+    constants::tss::{TSS2_BASE_RC_TRY_AGAIN, TSS2_RC_LAYER_MASK, TSS2_RC_SUCCESS},
+    tcti_ldr::TctiNameConf,
+    tss2_esys::{
+        size_t, Esys_GetPollHandles, Esys_SetTimeout, TSS2_RC, TSS2_TCTI_POLL_HANDLE,
+        TSS2_TCTI_TIMEOUT_BLOCK,
+    },
+    Context, Result, ReturnCode,
+};
+use log::{debug, error, warn};
+use malloced::Malloced;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::ptr::null_mut;
+use std::task::{self, Poll};
+use waiter::{wake_when_ready, Registration};
+
+/// Asynchronous version of the [Context].
+///
+/// # Details
+/// The command methods of the asynchronous context send the commands to the TPM
+/// using the asynchronous functions of the ESAPI and return futures that complete
+/// once the TPM has responded, so the thread is not blocked while the TPM is busy.
+/// The futures are woken up when the poll handles of the TCTI signal that the
+/// response is available.
+///
+/// Only one command can be executed at a time, which is ensured by the futures
+/// borrowing the context mutably. The context and the futures are `Send`, so
+/// the futures can be spawned on multi threaded runtimes.
+///
+/// The ESAPI cannot cancel a command once it has been sent. If a future is
+/// dropped before it has completed, the response of its command is received
+/// when the context is used next, by the next command or by
+/// [AsyncContext::finish_abandoned_command], and the output is discarded.
+///
+/// The sessions and the functionality that is not available asynchronously are
+/// accessed through the wrapped [Context], see [AsyncContext::context_mut].
+pub struct AsyncContext {
+    context: Context,
+    abandoned_command: Option<AbandonedCommand>,
+}
+
+/// The finish function of a command whose future has been dropped.
+type AbandonedCommand = Box<dyn FnMut(&mut Context) -> Result<Poll<()>> + Send>;
+
+impl AsyncContext {
+    /// Creates a new asynchronous context based on the desired TCTI.
+    ///
+    /// # Errors
+    /// See [Context::new].
+    pub fn new(tcti_name_conf: TctiNameConf) -> Result<Self> {
+        Context::new(tcti_name_conf).map(AsyncContext::from)
+    }
+
+    /// Returns a reference to the wrapped [Context].
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// Returns a mutable reference to the wrapped [Context].
+    ///
+    /// # Details
+    /// If the future of a command has been dropped before it completed, this
+    /// blocks until the TPM has responded to the command. The blocking can be
+    /// avoided by awaiting [AsyncContext::finish_abandoned_command] first.
+    pub fn context_mut(&mut self) -> &mut Context {
+        self.finish_abandoned_command_blocking();
+        &mut self.context
+    }
+
+    /// Returns the wrapped [Context].
+    ///
+    /// # Details
+    /// Blocks like [AsyncContext::context_mut] if the
+    /// future of a command has been dropped before it completed.
+    pub fn into_context(mut self) -> Context {
+        self.finish_abandoned_command_blocking();
+        self.context
+    }
+
+    /// Receives the response of the command whose future has been dropped
+    /// before it completed, if there is one, and discards the output.
+    ///
+    /// # Details
+    /// This is done by the command methods before they send their command,
+    /// so it only needs to be awaited before [AsyncContext::context_mut]
+    /// is called, in order not to block.
+    ///
+    /// # Errors
+    /// The error that the abandoned command has resulted in.
+    pub async fn finish_abandoned_command(&mut self) -> Result<()> {
+        match self.abandoned_command.take() {
+            Some(finish) => self.complete(finish).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Receives the response of the abandoned command, if there is one,
+    /// before the next command is sent. Its error is only logged, as it
+    /// does not concern the next command.
+    async fn finish_previous_command(&mut self) {
+        if let Err(error) = self.finish_abandoned_command().await {
+            warn!("The command that is no longer awaited failed: {}", error);
+        }
+    }
+
+    /// Blocks until the response of the abandoned command
+    /// has been received, if there is one.
+    fn finish_abandoned_command_blocking(&mut self) {
+        if let Some(mut finish) = self.abandoned_command.take() {
+            // The finish function blocks until the response has been received.
+            loop {
+                match finish(&mut self.context) {
+                    Ok(Poll::Pending) => continue,
+                    Ok(Poll::Ready(())) => break,
+                    Err(error) => {
+                        warn!("The command that is no longer awaited failed: {}", error);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns a future that completes the command that has been
+    /// started using the asynchronous function of the ESAPI.
+    ///
+    /// # Arguments
+    /// * `finish` - Function calling the finish function of the command. It
+    ///              returns `Poll::Pending` while the response has not been
+    ///              received, see [ensure_finished].
+    fn complete<T, F>(&mut self, finish: F) -> CommandFuture<'_, T, F>
+    where
+        T: 'static,
+        F: FnMut(&mut Context) -> Result<Poll<T>> + Send + Unpin + 'static,
+    {
+        CommandFuture {
+            async_context: self,
+            finish: Some(finish),
+            registration: None,
+            output: PhantomData,
+        }
+    }
+}
+
+impl std::fmt::Debug for AsyncContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncContext")
+            .field("context", &self.context)
+            .field("abandoned_command", &self.abandoned_command.is_some())
+            .finish()
+    }
+}
+
+impl From<Context> for AsyncContext {
+    fn from(context: Context) -> Self {
+        AsyncContext {
+            context,
+            abandoned_command: None,
+        }
+    }
+}
+
+impl From<AsyncContext> for Context {
+    /// Blocks like [AsyncContext::into_context].
+    fn from(async_context: AsyncContext) -> Self {
+        async_context.into_context()
+    }
+}
+
+/// Checks the return code of the finish function of a command.
+///
+/// # Returns
+/// `false` if the response of the TPM has not been received yet.
+///
+/// # Errors
+/// Generates the error indicated by the return code.
+fn ensure_finished<F>(tss2_rc: TSS2_RC, f: F) -> Result<bool>
+where
+    F: FnOnce(TSS2_RC),
+{
+    if tss2_rc & TSS2_RC_LAYER_MASK != 0 && tss2_rc & 0xFFFF == TSS2_BASE_RC_TRY_AGAIN {
+        Ok(false)
+    } else {
+        ReturnCode::ensure_success(tss2_rc, f).map(|_| true)
+    }
+}
+
+/// Future completing a command that has been started
+/// using the asynchronous functions of the ESAPI.
+struct CommandFuture<'a, T, F>
+where
+    T: 'static,
+    F: FnMut(&mut Context) -> Result<Poll<T>> + Send + Unpin + 'static,
+{
+    async_context: &'a mut AsyncContext,
+    /// The finish function, until the command has completed.
+    finish: Option<F>,
+    /// The registration with the waiter while the response is awaited.
+    registration: Option<Registration>,
+    output: PhantomData<fn() -> T>,
+}
+
+impl<T, F> Future for CommandFuture<'_, T, F>
+where
+    T: 'static,
+    F: FnMut(&mut Context) -> Result<Poll<T>> + Send + Unpin + 'static,
+{
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        // A previous registration is replaced by the one made below
+        // if the response still has not been received.
+        this.registration = None;
+        let finish = match this.finish.as_mut() {
+            Some(finish) => finish,
+            None => panic!("CommandFuture polled after completion"),
+        };
+        let context = &mut this.async_context.context;
+        // The timeout makes the finish function return immediately
+        // if the response has not been received.
+        context.set_timeout(0)?;
+        let result = finish(context);
+        let restore_result = context.set_timeout(TSS2_TCTI_TIMEOUT_BLOCK);
+        match result {
+            Ok(Poll::Pending) => {
+                restore_result?;
+                this.registration = wake_when_ready(context.poll_handles(), cx.waker().clone());
+                Poll::Pending
+            }
+            Ok(Poll::Ready(output)) => {
+                this.finish = None;
+                Poll::Ready(restore_result.map(|_| output))
+            }
+            Err(error) => {
+                this.finish = None;
+                Poll::Ready(Err(error))
+            }
+        }
+    }
+}
+
+impl<T, F> Drop for CommandFuture<'_, T, F>
+where
+    T: 'static,
+    F: FnMut(&mut Context) -> Result<Poll<T>> + Send + Unpin + 'static,
+{
+    /// Hands the command over to the context if it has not completed.
+    ///
+    /// # Details
+    /// The command cannot be cancelled and the context cannot be used before
+    /// the response has been received, so the response is received the next
+    /// time the context is used instead of blocking the executor here.
+    fn drop(&mut self) {
+        if let Some(mut finish) = self.finish.take() {
+            debug!("Abandoning a command that is no longer awaited.");
+            self.async_context.abandoned_command = Some(Box::new(move |context: &mut Context| {
+                finish(context).map(|poll| poll.map(|_| ()))
+            }));
+        }
+    }
+}
+
+impl Context {
+    /// Sets the timeout of the finish functions of the ESAPI.
+    fn set_timeout(&mut self, timeout: i32) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe { Esys_SetTimeout(self.mut_context(), timeout) },
+            |ret| {
+                error!("Error when setting the timeout: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Returns the poll handles of the TCTI or no handles
+    /// if the TCTI does not support them.
+    fn poll_handles(&mut self) -> Vec<TSS2_TCTI_POLL_HANDLE> {
+        let mut poll_handles_ptr = null_mut();
+        let mut count: size_t = 0;
+        let ret =
+            unsafe { Esys_GetPollHandles(self.mut_context(), &mut poll_handles_ptr, &mut count) };
+        if ret != TSS2_RC_SUCCESS || poll_handles_ptr.is_null() {
+            debug!("No poll handles available: {:#010X}", ret);
+            return Vec::new();
+        }
+        let poll_handles = unsafe {
+            Malloced::<[TSS2_TCTI_POLL_HANDLE]>::slice_from_raw_parts(
+                poll_handles_ptr,
+                count as usize,
+            )
+        };
+        poll_handles.to_vec()
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::{ensure_finished, AsyncContext};
+use crate::{
+    handles::PcrHandle,
+    structures::{DigestList, DigestValues, PcrSelectionList},
+    tss2_esys::{
+        Esys_PCR_Extend_Async, Esys_PCR_Extend_Finish, Esys_PCR_Read_Async, Esys_PCR_Read_Finish,
+    },
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use std::ptr::null_mut;
+use std::task::Poll;
+
+impl AsyncContext {
+    /// Extends a PCR, see [Context::pcr_extend].
+    pub async fn pcr_extend(&mut self, pcr_handle: PcrHandle, digests: DigestValues) -> Result<()> {
+        self.finish_previous_command().await;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_Extend_Async(
+                    self.context.mut_context(),
+                    pcr_handle.into(),
+                    self.context.optional_session_1(),
+                    self.context.optional_session_2(),
+                    self.context.optional_session_3(),
+                    &digests.try_into()?,
+                )
+            },
+            |ret| {
+                error!("Error when extending PCR: {:#010X}", ret);
+            },
+        )?;
+
+        self.complete(|context| {
+            let finished = ensure_finished(
+                unsafe { Esys_PCR_Extend_Finish(context.mut_context()) },
+                |ret| {
+                    error!("Error when extending PCR: {:#010X}", ret);
+                },
+            )?;
+            Ok(if finished {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            })
+        })
+        .await
+    }
+
+    /// Reads the values of PCRs, see [Context::pcr_read].
+    pub async fn pcr_read(
+        &mut self,
+        pcr_selection_list: PcrSelectionList,
+    ) -> Result<(u32, PcrSelectionList, DigestList)> {
+        self.finish_previous_command().await;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_Read_Async(
+                    self.context.mut_context(),
+                    self.context.optional_session_1(),
+                    self.context.optional_session_2(),
+                    self.context.optional_session_3(),
+                    &pcr_selection_list.into(),
+                )
+            },
+            |ret| {
+                error!("Error when reading PCR: {:#010X}", ret);
+            },
+        )?;
+
+        self.complete(|context| {
+            let mut pcr_update_counter: u32 = 0;
+            let mut pcr_selection_out_ptr = null_mut();
+            let mut pcr_values_ptr = null_mut();
+            let finished = ensure_finished(
+                unsafe {
+                    Esys_PCR_Read_Finish(
+                        context.mut_context(),
+                        &mut pcr_update_counter,
+                        &mut pcr_selection_out_ptr,
+                        &mut pcr_values_ptr,
+                    )
+                },
+                |ret| {
+                    error!("Error when reading PCR: {:#010X}", ret);
+                },
+            )?;
+            if !finished {
+                return Ok(Poll::Pending);
+            }
+            Ok(Poll::Ready((
+                pcr_update_counter,
+                PcrSelectionList::try_from(Context::ffi_data_to_owned(pcr_selection_out_ptr))?,
+                DigestList::try_from(Context::ffi_data_to_owned(pcr_values_ptr))?,
+            )))
+        })
+        .await
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::{ensure_finished, AsyncContext};
+use crate::{
+    context::handle_manager::HandleDropAction,
+    handles::{AuthHandle, NvIndexHandle, ObjectHandle},
+    interface_types::reserved_handles::{NvAuth, Provision},
+    structures::{Auth, MaxNvBuffer, NvPublic},
+    tss2_esys::{
+        Esys_NV_DefineSpace_Async, Esys_NV_DefineSpace_Finish, Esys_NV_Read_Async,
+        Esys_NV_Read_Finish, Esys_NV_UndefineSpace_Async, Esys_NV_UndefineSpace_Finish,
+        Esys_NV_Write_Async, Esys_NV_Write_Finish,
+    },
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use std::ptr::null_mut;
+use std::task::Poll;
+
+impl AsyncContext {
+    /// Allocates an index in the non volatile storage, see [Context::nv_define_space].
+    pub async fn nv_define_space(
+        &mut self,
+        nv_auth: Provision,
+        auth: Option<Auth>,
+        public_info: NvPublic,
+    ) -> Result<NvIndexHandle> {
+        self.finish_previous_command().await;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_DefineSpace_Async(
+                    self.context.mut_context(),
+                    AuthHandle::from(nv_auth).into(),
+                    self.context.required_session_1()?,
+                    self.context.optional_session_2(),
+                    self.context.optional_session_3(),
+                    &auth.unwrap_or_default().into(),
+                    &public_info.try_into()?,
+                )
+            },
+            |ret| {
+                error!("Error when defining NV space: {:#010X}", ret);
+            },
+        )?;
+
+        self.complete(|context| {
+            let mut nv_handle = ObjectHandle::None.into();
+            let finished = ensure_finished(
+                unsafe { Esys_NV_DefineSpace_Finish(context.mut_context(), &mut nv_handle) },
+                |ret| {
+                    error!("Error when defining NV space: {:#010X}", ret);
+                },
+            )?;
+            if !finished {
+                return Ok(Poll::Pending);
+            }
+            context
+                .handle_manager
+                .add_handle(nv_handle.into(), HandleDropAction::Close)?;
+            Ok(Poll::Ready(NvIndexHandle::from(nv_handle)))
+        })
+        .await
+    }
+
+    /// Deletes an index in the non volatile storage, see [Context::nv_undefine_space].
+    pub async fn nv_undefine_space(
+        &mut self,
+        nv_auth: Provision,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<()> {
+        self.finish_previous_command().await;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_UndefineSpace_Async(
+                    self.context.mut_context(),
+                    AuthHandle::from(nv_auth).into(),
+                    nv_index_handle.into(),
+                    self.context.required_session_1()?,
+                    self.context.optional_session_2(),
+                    self.context.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error when undefining NV space: {:#010X}", ret);
+            },
+        )?;
+
+        self.complete(move |context| {
+            let finished = ensure_finished(
+                unsafe { Esys_NV_UndefineSpace_Finish(context.mut_context()) },
+                |ret| {
+                    error!("Error when undefining NV space: {:#010X}", ret);
+                },
+            )?;
+            if !finished {
+                return Ok(Poll::Pending);
+            }
+            context
+                .handle_manager
+                .set_as_closed(nv_index_handle.into())
+                .map(Poll::Ready)
+        })
+        .await
+    }
+
+    /// Writes data to an NV memory area, see [Context::nv_write].
+    pub async fn nv_write(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        data: MaxNvBuffer,
+        offset: u16,
+    ) -> Result<()> {
+        self.finish_previous_command().await;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_Write_Async(
+                    self.context.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.context.required_session_1()?,
+                    self.context.optional_session_2(),
+                    self.context.optional_session_3(),
+                    &data.into(),
+                    offset,
+                )
+            },
+            |ret| {
+                error!("Error when writing NV: {:#010X}", ret);
+            },
+        )?;
+
+        self.complete(|context| {
+            let finished = ensure_finished(
+                unsafe { Esys_NV_Write_Finish(context.mut_context()) },
+                |ret| {
+                    error!("Error when writing NV: {:#010X}", ret);
+                },
+            )?;
+            Ok(if finished {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            })
+        })
+        .await
+    }
+
+    /// Reads data from an NV memory area, see [Context::nv_read].
+    pub async fn nv_read(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        size: u16,
+        offset: u16,
+    ) -> Result<MaxNvBuffer> {
+        self.finish_previous_command().await;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_Read_Async(
+                    self.context.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.context.required_session_1()?,
+                    self.context.optional_session_2(),
+                    self.context.optional_session_3(),
+                    size,
+                    offset,
+                )
+            },
+            |ret| {
+                error!("Error when reading NV: {:#010X}", ret);
+            },
+        )?;
+
+        self.complete(|context| {
+            let mut data_ptr = null_mut();
+            let finished = ensure_finished(
+                unsafe { Esys_NV_Read_Finish(context.mut_context(), &mut data_ptr) },
+                |ret| {
+                    error!("Error when reading NV: {:#010X}", ret);
+                },
+            )?;
+            if !finished {
+                return Ok(Poll::Pending);
+            }
+            MaxNvBuffer::try_from(Context::ffi_data_to_owned(data_ptr)).map(Poll::Ready)
+        })
+        .await
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::{ensure_finished, AsyncContext};
+use crate::{
+    context::{
+        handle_manager::HandleDropAction,
+        tpm_commands::object_commands::{
+            create_command_input::CreateCommandInputHandler,
+            create_command_output::CreateCommandOutputHandler,
+        },
+    },
+    handles::{KeyHandle, ObjectHandle},
+    interface_types::reserved_handles::Hierarchy,
+    structures::{
+        Auth, CreateKeyResult, CreatePrimaryKeyResult, CreationData, CreationTicket, Data, Digest,
+        Name, PcrSelectionList, Private, Public, SensitiveCreate, SensitiveData,
+    },
+    tss2_esys::{
+        Esys_CreatePrimary_Async, Esys_CreatePrimary_Finish, Esys_Create_Async, Esys_Create_Finish,
+        Esys_Load_Async, Esys_Load_Finish, Esys_ReadPublic_Async, Esys_ReadPublic_Finish,
+    },
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use std::ptr::null_mut;
+use std::task::Poll;
+
+impl AsyncContext {
+    /// Creates a primary key, see [Context::create_primary].
+    pub async fn create_primary(
+        &mut self,
+        primary_handle: Hierarchy,
+        public: Public,
+        auth_value: Option<Auth>,
+        initial_data: Option<SensitiveData>,
+        outside_info: Option<Data>,
+        creation_pcrs: Option<PcrSelectionList>,
+    ) -> Result<CreatePrimaryKeyResult> {
+        self.finish_previous_command().await;
+        let sensitive_create = SensitiveCreate::new(
+            auth_value.unwrap_or_default(),
+            initial_data.unwrap_or_default(),
+        );
+        let creation_pcrs = PcrSelectionList::list_from_option(creation_pcrs);
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_CreatePrimary_Async(
+                    self.context.mut_context(),
+                    ObjectHandle::from(primary_handle).into(),
+                    self.context.optional_session_1(),
+                    self.context.optional_session_2(),
+                    self.context.optional_session_3(),
+                    &sensitive_create.try_into()?,
+                    &public.try_into()?,
+                    &outside_info.unwrap_or_default().into(),
+                    &creation_pcrs.into(),
+                )
+            },
+            |ret| {
+                error!("Error in creating primary key: {:#010X}", ret);
+            },
+        )?;
+
+        self.complete(|context| {
+            let mut object_handle = ObjectHandle::None.into();
+            let mut out_public_ptr = null_mut();
+            let mut creation_data_ptr = null_mut();
+            let mut creation_hash_ptr = null_mut();
+            let mut creation_ticket_ptr = null_mut();
+            let finished = ensure_finished(
+                unsafe {
+                    Esys_CreatePrimary_Finish(
+                        context.mut_context(),
+                        &mut object_handle,
+                        &mut out_public_ptr,
+                        &mut creation_data_ptr,
+                        &mut creation_hash_ptr,
+                        &mut creation_ticket_ptr,
+                    )
+                },
+                |ret| {
+                    error!("Error in creating primary key: {:#010X}", ret);
+                },
+            )?;
+            if !finished {
+                return Ok(Poll::Pending);
+            }
+
+            let out_public_owned = Context::ffi_data_to_owned(out_public_ptr);
+            let creation_data_owned = Context::ffi_data_to_owned(creation_data_ptr);
+            let creation_hash_owned = Context::ffi_data_to_owned(creation_hash_ptr);
+            let creation_ticket_owned = Context::ffi_data_to_owned(creation_ticket_ptr);
+            let primary_key_handle = KeyHandle::from(object_handle);
+            context
+                .handle_manager
+                .add_handle(primary_key_handle.into(), HandleDropAction::Flush)?;
+
+            Ok(Poll::Ready(CreatePrimaryKeyResult {
+                key_handle: primary_key_handle,
+                out_public: Public::try_from(out_public_owned)?,
+                creation_data: CreationData::try_from(creation_data_owned)?,
+                creation_hash: Digest::try_from(creation_hash_owned)?,
+                creation_ticket: CreationTicket::try_from(creation_ticket_owned)?,
+            }))
+        })
+        .await
+    }
+
+    /// Creates a key, see [Context::create].
+    // TODO: Fix when compacting the arguments into a struct
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &mut self,
+        parent_handle: KeyHandle,
+        public: Public,
+        auth_value: Option<Auth>,
+        sensitive_data: Option<SensitiveData>,
+        outside_info: Option<Data>,
+        creation_pcrs: Option<PcrSelectionList>,
+    ) -> Result<CreateKeyResult> {
+        self.finish_previous_command().await;
+        {
+            let input_parameters = CreateCommandInputHandler::create(
+                parent_handle,
+                public,
+                auth_value,
+                sensitive_data,
+                outside_info,
+                creation_pcrs,
+            )?;
+            ReturnCode::ensure_success(
+                unsafe {
+                    Esys_Create_Async(
+                        self.context.mut_context(),
+                        input_parameters.ffi_in_parent_handle(),
+                        self.context.optional_session_1(),
+                        self.context.optional_session_2(),
+                        self.context.optional_session_3(),
+                        input_parameters.ffi_in_sensitive(),
+                        input_parameters.ffi_in_public(),
+                        input_parameters.ffi_outside_info(),
+                        input_parameters.ffi_creation_pcr(),
+                    )
+                },
+                |ret| {
+                    error!("Error in creating derived key: {:#010X}", ret);
+                },
+            )?;
+        }
+
+        self.complete(|context| {
+            let mut output_parameters = CreateCommandOutputHandler::new();
+            let finished = ensure_finished(
+                unsafe {
+                    Esys_Create_Finish(
+                        context.mut_context(),
+                        output_parameters.ffi_out_private_ptr(),
+                        output_parameters.ffi_out_public_ptr(),
+                        output_parameters.ffi_creation_data_ptr(),
+                        output_parameters.ffi_creation_hash_ptr(),
+                        output_parameters.ffi_creation_ticket_ptr(),
+                    )
+                },
+                |ret| {
+                    error!("Error in creating derived key: {:#010X}", ret);
+                },
+            )?;
+            if !finished {
+                return Ok(Poll::Pending);
+            }
+            output_parameters.try_into().map(Poll::Ready)
+        })
+        .await
+    }
+
+    /// Loads a previously generated key, see [Context::load].
+    pub async fn load(
+        &mut self,
+        parent_handle: KeyHandle,
+        private: Private,
+        public: Public,
+    ) -> Result<KeyHandle> {
+        self.finish_previous_command().await;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Load_Async(
+                    self.context.mut_context(),
+                    parent_handle.into(),
+                    self.context.optional_session_1(),
+                    self.context.optional_session_2(),
+                    self.context.optional_session_3(),
+                    &private.into(),
+                    &public.try_into()?,
+                )
+            },
+            |ret| {
+                error!("Error in loading: {:#010X}", ret);
+            },
+        )?;
+
+        self.complete(|context| {
+            let mut object_handle = ObjectHandle::None.into();
+            let finished = ensure_finished(
+                unsafe { Esys_Load_Finish(context.mut_context(), &mut object_handle) },
+                |ret| {
+                    error!("Error in loading: {:#010X}", ret);
+                },
+            )?;
+            if !finished {
+                return Ok(Poll::Pending);
+            }
+            let key_handle = KeyHandle::from(object_handle);
+            context
+                .handle_manager
+                .add_handle(key_handle.into(), HandleDropAction::Flush)?;
+            Ok(Poll::Ready(key_handle))
+        })
+        .await
+    }
+
+    /// Reads the public part of a key, see [Context::read_public].
+    pub async fn read_public(&mut self, key_handle: KeyHandle) -> Result<(Public, Name, Name)> {
+        self.finish_previous_command().await;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ReadPublic_Async(
+                    self.context.mut_context(),
+                    key_handle.into(),
+                    self.context.optional_session_1(),
+                    self.context.optional_session_2(),
+                    self.context.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error in reading public part of object: {:#010X}", ret);
+            },
+        )?;
+
+        self.complete(|context| {
+            let mut out_public_ptr = null_mut();
+            let mut name_ptr = null_mut();
+            let mut qualified_name_ptr = null_mut();
+            let finished = ensure_finished(
+                unsafe {
+                    Esys_ReadPublic_Finish(
+                        context.mut_context(),
+                        &mut out_public_ptr,
+                        &mut name_ptr,
+                        &mut qualified_name_ptr,
+                    )
+                },
+                |ret| {
+                    error!("Error in reading public part of object: {:#010X}", ret);
+                },
+            )?;
+            if !finished {
+                return Ok(Poll::Pending);
+            }
+            Ok(Poll::Ready((
+                Public::try_from(Context::ffi_data_to_owned(out_public_ptr))?,
+                Name::try_from(Context::ffi_data_to_owned(name_ptr))?,
+                Name::try_from(Context::ffi_data_to_owned(qualified_name_ptr))?,
+            )))
+        })
+        .await
+    }
+}
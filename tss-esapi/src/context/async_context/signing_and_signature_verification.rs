@@ -0,0 +1,102 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::{ensure_finished, AsyncContext};
+use crate::{
+    handles::KeyHandle,
+    structures::{Digest, HashcheckTicket, Signature, SignatureScheme, VerifiedTicket},
+    tss2_esys::{
+        Esys_Sign_Async, Esys_Sign_Finish, Esys_VerifySignature_Async, Esys_VerifySignature_Finish,
+    },
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use std::ptr::null_mut;
+use std::task::Poll;
+
+impl AsyncContext {
+    /// Verifies a signature, see [Context::verify_signature].
+    pub async fn verify_signature(
+        &mut self,
+        key_handle: KeyHandle,
+        digest: Digest,
+        signature: Signature,
+    ) -> Result<VerifiedTicket> {
+        self.finish_previous_command().await;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_VerifySignature_Async(
+                    self.context.mut_context(),
+                    key_handle.into(),
+                    self.context.optional_session_1(),
+                    self.context.optional_session_2(),
+                    self.context.optional_session_3(),
+                    &digest.into(),
+                    &signature.try_into()?,
+                )
+            },
+            |ret| {
+                error!("Error when verifying signature: {:#010X}", ret);
+            },
+        )?;
+
+        self.complete(|context| {
+            let mut validation_ptr = null_mut();
+            let finished = ensure_finished(
+                unsafe { Esys_VerifySignature_Finish(context.mut_context(), &mut validation_ptr) },
+                |ret| {
+                    error!("Error when verifying signature: {:#010X}", ret);
+                },
+            )?;
+            if !finished {
+                return Ok(Poll::Pending);
+            }
+            VerifiedTicket::try_from(Context::ffi_data_to_owned(validation_ptr)).map(Poll::Ready)
+        })
+        .await
+    }
+
+    /// Signs a digest, see [Context::sign].
+    pub async fn sign(
+        &mut self,
+        key_handle: KeyHandle,
+        digest: Digest,
+        scheme: SignatureScheme,
+        validation: impl Into<Option<HashcheckTicket>>,
+    ) -> Result<Signature> {
+        self.finish_previous_command().await;
+        let validation_ticket = validation.into().unwrap_or_default().try_into()?;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Sign_Async(
+                    self.context.mut_context(),
+                    key_handle.into(),
+                    self.context.required_session_1()?,
+                    self.context.optional_session_2(),
+                    self.context.optional_session_3(),
+                    &digest.into(),
+                    &scheme.into(),
+                    &validation_ticket,
+                )
+            },
+            |ret| {
+                error!("Error when signing: {:#010X}", ret);
+            },
+        )?;
+
+        self.complete(|context| {
+            let mut signature_ptr = null_mut();
+            let finished = ensure_finished(
+                unsafe { Esys_Sign_Finish(context.mut_context(), &mut signature_ptr) },
+                |ret| {
+                    error!("Error when signing: {:#010X}", ret);
+                },
+            )?;
+            if !finished {
+                return Ok(Poll::Pending);
+            }
+            Signature::try_from(Context::ffi_data_to_owned(signature_ptr)).map(Poll::Ready)
+        })
+        .await
+    }
+}
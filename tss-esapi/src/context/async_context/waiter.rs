@@ -0,0 +1,265 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::tss2_esys::TSS2_TCTI_POLL_HANDLE;
+use log::error;
+use std::io;
+use std::mem;
+use std::sync::{Arc, Mutex};
+use std::task::Waker;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The longest time that passes before a pending command is polled again,
+/// even if the TCTI has not signaled that the response is available.
+const POLL_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// The waiter shared by all the pending commands, started on first use.
+static WAITER: Mutex<Option<Waiter>> = Mutex::new(None);
+
+/// Registration of a task with the waiter.
+///
+/// # Details
+/// The registration is removed from the waiter when this is dropped,
+/// so the handles are no longer polled once the command has completed.
+#[derive(Debug)]
+pub(super) struct Registration {
+    id: u64,
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        if let Some(waiter) = lock(&WAITER).as_ref() {
+            waiter.deregister(self.id);
+        }
+    }
+}
+
+/// A task waiting for the response of the TPM.
+struct PendingTask {
+    id: u64,
+    poll_fds: Vec<libc::pollfd>,
+    deadline: Instant,
+    waker: Waker,
+}
+
+/// Changes to the pending tasks that have not been picked up by the thread.
+#[derive(Default)]
+struct Changes {
+    added: Vec<PendingTask>,
+    removed: Vec<u64>,
+}
+
+/// Handle to the thread that polls the handles of all the pending commands.
+///
+/// # Details
+/// Changes to the pending tasks are signaled to the thread through a pipe,
+/// which is polled together with the handles of the pending tasks.
+struct Waiter {
+    changes: Arc<Mutex<Changes>>,
+    notify_fd: libc::c_int,
+    next_id: u64,
+}
+
+impl Waiter {
+    /// Creates the pipe and spawns the thread of the waiter.
+    fn start() -> io::Result<Self> {
+        let fds = create_pipe()?;
+        let [read_fd, write_fd] = fds;
+        let changes = Arc::new(Mutex::new(Changes::default()));
+        let thread_changes = changes.clone();
+        if let Err(error) = thread::Builder::new()
+            .name(String::from("tss-esapi-waiter"))
+            .spawn(move || run(read_fd, &thread_changes))
+        {
+            close_pipe(fds);
+            return Err(error);
+        }
+        Ok(Waiter {
+            changes,
+            notify_fd: write_fd,
+            next_id: 0,
+        })
+    }
+
+    /// Adds the pending task and notifies the thread.
+    fn register(&mut self, poll_fds: Vec<libc::pollfd>, waker: Waker) -> Registration {
+        let id = self.next_id;
+        self.next_id += 1;
+        lock(&self.changes).added.push(PendingTask {
+            id,
+            poll_fds,
+            deadline: Instant::now() + POLL_TIMEOUT,
+            waker,
+        });
+        self.notify();
+        Registration { id }
+    }
+
+    /// Removes the pending task and notifies the thread.
+    fn deregister(&self, id: u64) {
+        lock(&self.changes).removed.push(id);
+        self.notify();
+    }
+
+    /// Makes the thread pick up the changes.
+    fn notify(&self) {
+        let byte = 1u8;
+        // A full pipe already makes the thread pick up the changes.
+        let _ = unsafe { libc::write(self.notify_fd, std::ptr::addr_of!(byte).cast(), 1) };
+    }
+}
+
+/// Wakes the task once one of the poll handles signals that the response
+/// is available or, at the latest, after the poll timeout.
+///
+/// # Details
+/// The handles of all the pending commands are polled by a single thread.
+/// If the thread cannot be started, the task is woken up immediately.
+///
+/// # Returns
+/// The registration, which has to be kept until the task is woken up.
+pub(super) fn wake_when_ready(
+    poll_handles: Vec<TSS2_TCTI_POLL_HANDLE>,
+    waker: Waker,
+) -> Option<Registration> {
+    let poll_fds = poll_handles
+        .iter()
+        .map(|poll_handle| libc::pollfd {
+            fd: poll_handle.fd,
+            events: poll_handle.events,
+            revents: 0,
+        })
+        .collect();
+    let mut waiter = lock(&WAITER);
+    if waiter.is_none() {
+        match Waiter::start() {
+            Ok(started) => *waiter = Some(started),
+            Err(error) => {
+                error!("Failed to start the thread waiting for the TPM: {}", error);
+                waker.wake();
+                return None;
+            }
+        }
+    }
+    waiter
+        .as_mut()
+        .map(|waiter| waiter.register(poll_fds, waker))
+}
+
+/// Loop of the waiter thread.
+fn run(notify_fd: libc::c_int, changes: &Mutex<Changes>) {
+    let mut pending: Vec<PendingTask> = Vec::new();
+    loop {
+        {
+            let mut changes = lock(changes);
+            pending.append(&mut changes.added);
+            let removed = mem::take(&mut changes.removed);
+            pending.retain(|task| !removed.contains(&task.id));
+        }
+        let now = Instant::now();
+        pending.retain(|task| {
+            let expired = task.deadline <= now;
+            if expired {
+                task.waker.wake_by_ref();
+            }
+            !expired
+        });
+
+        let timeout =
+            pending
+                .iter()
+                .map(|task| task.deadline - now)
+                .min()
+                .map_or(-1, |remaining| {
+                    // Rounded up, so the deadline has passed when poll returns.
+                    ((remaining.as_nanos() + 999_999) / 1_000_000) as libc::c_int
+                });
+        let mut poll_fds = vec![libc::pollfd {
+            fd: notify_fd,
+            events: libc::POLLIN,
+            revents: 0,
+        }];
+        for task in &pending {
+            poll_fds.extend_from_slice(&task.poll_fds);
+        }
+        let ret = unsafe {
+            libc::poll(
+                poll_fds.as_mut_ptr(),
+                poll_fds.len() as libc::nfds_t,
+                timeout,
+            )
+        };
+        if ret < 0 {
+            let error = io::Error::last_os_error();
+            if error.kind() != io::ErrorKind::Interrupted {
+                error!("Failed to poll the handles of the TCTI: {}", error);
+                for task in mem::take(&mut pending) {
+                    task.waker.wake();
+                }
+            }
+            continue;
+        }
+
+        if poll_fds[0].revents != 0 {
+            let mut buffer = [0u8; 64];
+            while unsafe { libc::read(notify_fd, buffer.as_mut_ptr().cast(), buffer.len()) } > 0 {}
+        }
+        let mut offset = 1;
+        pending.retain(|task| {
+            let revents = &poll_fds[offset..offset + task.poll_fds.len()];
+            offset += task.poll_fds.len();
+            let ready = revents.iter().any(|poll_fd| poll_fd.revents != 0);
+            if ready {
+                task.waker.wake_by_ref();
+            }
+            !ready
+        });
+    }
+}
+
+/// Creates the non-blocking pipe used to notify the thread,
+/// which is closed when the process executes another program.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn create_pipe() -> io::Result<[libc::c_int; 2]> {
+    let mut fds: [libc::c_int; 2] = [0; 2];
+    if unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_CLOEXEC | libc::O_NONBLOCK) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(fds)
+}
+
+/// Creates the non-blocking pipe used to notify the thread,
+/// which is closed when the process executes another program.
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn create_pipe() -> io::Result<[libc::c_int; 2]> {
+    let mut fds: [libc::c_int; 2] = [0; 2];
+    if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    for fd in fds {
+        let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+        if flags < 0
+            || unsafe { libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) } < 0
+            || unsafe { libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) } < 0
+        {
+            let error = io::Error::last_os_error();
+            close_pipe(fds);
+            return Err(error);
+        }
+    }
+    Ok(fds)
+}
+
+/// Locks the mutex, ignoring poisoning as the data stays consistent.
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Closes both ends of the pipe.
+fn close_pipe(fds: [libc::c_int; 2]) {
+    for fd in fds {
+        let _ = unsafe { libc::close(fd) };
+    }
+}
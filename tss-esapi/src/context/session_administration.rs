@@ -4,11 +4,21 @@ use crate::{
     attributes::{SessionAttributes, SessionAttributesMask},
     handles::SessionHandle,
     interface_types::session_handles::AuthSession,
-    tss2_esys::{Esys_TRSess_GetAttributes, Esys_TRSess_SetAttributes},
+    structures::Nonce,
+    tss2_esys::{Esys_TRSess_GetAttributes, Esys_TRSess_GetNonceTPM, Esys_TRSess_SetAttributes},
     Context, Result, ReturnCode,
 };
 use log::error;
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
+use std::ptr::null_mut;
+
+// The Esys_TRSess_GetAuthRequired function was first added
+// to the ESAPI in the 3.0.0 version of the tpm2-tss.
+#[cfg(has_esys_trsess_get_auth_required)]
+use crate::{
+    interface_types::YesNo,
+    tss2_esys::{Esys_TRSess_GetAuthRequired, TPMI_YES_NO},
+};
 
 impl Context {
     /// Set the given attributes on a given session.
@@ -51,5 +61,52 @@ impl Context {
         Ok(SessionAttributes(flags))
     }
 
-    // Missing function: Esys_TRSess_GetNonceTPM
+    /// Get the most recent nonce produced by the TPM for the session.
+    ///
+    /// # Details
+    /// The nonce is needed when computing authorizations outside of the
+    /// TPM, e.g. the `aHash` that is signed for [Context::policy_signed].
+    pub fn tr_sess_get_nonce_tpm(&mut self, session: AuthSession) -> Result<Nonce> {
+        let mut nonce_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_TRSess_GetNonceTPM(
+                    self.mut_context(),
+                    SessionHandle::from(session).into(),
+                    &mut nonce_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when getting session nonceTPM: {:#010X}", ret);
+            },
+        )?;
+        Nonce::try_from(Context::ffi_data_to_owned(nonce_ptr))
+    }
+
+    /// Get whether the policy of the session requires an authorization value.
+    ///
+    /// # Details
+    /// Returns `true` when the policy session has been extended by
+    /// [Context::policy_password] or [Context::policy_auth_value]
+    /// and therefore needs the authorization value of the object.
+    #[cfg(has_esys_trsess_get_auth_required)]
+    pub fn tr_sess_get_auth_required(&mut self, session: AuthSession) -> Result<bool> {
+        let mut auth_needed: TPMI_YES_NO = YesNo::No.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_TRSess_GetAuthRequired(
+                    self.mut_context(),
+                    SessionHandle::from(session).into(),
+                    &mut auth_needed,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when getting session authorization requirement: {:#010X}",
+                    ret
+                );
+            },
+        )?;
+        Ok(YesNo::try_from(auth_needed)?.into())
+    }
 }
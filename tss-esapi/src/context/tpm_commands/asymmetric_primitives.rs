@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     handles::KeyHandle,
+    interface_types::{algorithm::EccKeyExchangeAlgorithm, ecc::EccCurve},
     structures::Data,
-    structures::{EccPoint, PublicKeyRsa, RsaDecryptionScheme},
-    tss2_esys::{Esys_ECDH_KeyGen, Esys_ECDH_ZGen, Esys_RSA_Decrypt, Esys_RSA_Encrypt},
+    structures::{AlgorithmDetailEcc, EccPoint, PublicKeyRsa, RsaDecryptionScheme},
+    tss2_esys::{
+        Esys_ECC_Parameters, Esys_ECDH_KeyGen, Esys_ECDH_ZGen, Esys_RSA_Decrypt, Esys_RSA_Encrypt,
+        Esys_ZGen_2Phase,
+    },
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -339,6 +343,114 @@ impl Context {
         EccPoint::try_from(out_point.point)
     }
 
-    // Missing function: ECC_Parameters
-    // Missing function: ZGen_2Phase
+    /// Retrieves the parameters of an ECC curve.
+    ///
+    /// # Arguments
+    /// * `curve` - The [EccCurve] for which the parameters are retrieved.
+    ///
+    /// # Details
+    /// This command returns the parameters of the curve as they are
+    /// implemented by the TPM. An error is returned if the TPM does
+    /// not implement the curve.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf, interface_types::ecc::EccCurve};
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let parameters = context
+    ///     .ecc_parameters(EccCurve::NistP256)
+    ///     .expect("Call to ecc_parameters failed");
+    /// assert_eq!(parameters.curve(), EccCurve::NistP256);
+    /// assert_eq!(parameters.key_size(), 256);
+    /// ```
+    pub fn ecc_parameters(&mut self, curve: EccCurve) -> Result<AlgorithmDetailEcc> {
+        let mut parameters_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ECC_Parameters(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    curve.into(),
+                    &mut parameters_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when retrieving ECC parameters: {:#010X}", ret);
+            },
+        )?;
+
+        AlgorithmDetailEcc::try_from(Context::ffi_data_to_owned(parameters_ptr))
+    }
+
+    /// Performs the second phase of a two-phase key exchange.
+    ///
+    /// # Arguments
+    /// * `key_a` - A [KeyHandle] of the static ECC key of the party
+    ///             performing the computation.
+    /// * `in_qs_b` - The static public [EccPoint] of the other party.
+    /// * `in_qe_b` - The ephemeral public [EccPoint] of the other party.
+    /// * `in_scheme` - The [EccKeyExchangeAlgorithm] that is used in the
+    ///                 key exchange.
+    /// * `counter` - The counter value returned by [Context::ec_ephemeral]
+    ///               when the ephemeral key of the party performing the
+    ///               computation was created.
+    ///
+    /// # Details
+    /// This command uses the TPM to compute the Z values of a two-phase key
+    /// exchange protocol (e.g. ECMQV or SM2). The scheme of the key referenced
+    /// by `key_a` has to be either `Null` or the same as `in_scheme`.
+    ///
+    /// This command requires authorization for the key so an authorization
+    /// session needs to be set.
+    ///
+    /// # Returns
+    /// A tuple containing the two Z values `out_z1` and `out_z2`.
+    pub fn zgen_2phase(
+        &mut self,
+        key_a: KeyHandle,
+        in_qs_b: EccPoint,
+        in_qe_b: EccPoint,
+        in_scheme: EccKeyExchangeAlgorithm,
+        counter: u16,
+    ) -> Result<(EccPoint, EccPoint)> {
+        let mut out_z1_ptr = null_mut();
+        let mut out_z2_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ZGen_2Phase(
+                    self.mut_context(),
+                    key_a.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &in_qs_b.into(),
+                    &in_qe_b.into(),
+                    in_scheme.into(),
+                    counter,
+                    &mut out_z1_ptr,
+                    &mut out_z2_ptr,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when performing two-phase key exchange: {:#010X}",
+                    ret
+                );
+            },
+        )?;
+
+        let out_z1 = Context::ffi_data_to_owned(out_z1_ptr);
+        let out_z2 = Context::ffi_data_to_owned(out_z2_ptr);
+        Ok((
+            EccPoint::try_from(out_z1.point)?,
+            EccPoint::try_from(out_z2.point)?,
+        ))
+    }
 }
@@ -2,8 +2,179 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::Context;
 
+// The attached component commands were first added to the ESAPI in the
+// 4.0.0 version of the tpm2-tss and are missing from the bundled bindings,
+// so they require the bindings to be generated.
+#[cfg(has_esys_ac_commands)]
+use crate::{
+    constants::AcCapability,
+    handles::{AuthHandle, ObjectHandle, SessionHandle},
+    interface_types::{reserved_handles::NvAuth, session_handles::PolicySession, YesNo},
+    structures::{AcCapabilityList, AcOutput, MaxBuffer, Name},
+    tss2_esys::{Esys_AC_GetCapability, Esys_AC_Send, Esys_Policy_AC_SendSelect},
+    Result, ReturnCode,
+};
+#[cfg(has_esys_ac_commands)]
+use log::error;
+#[cfg(has_esys_ac_commands)]
+use std::convert::TryFrom;
+#[cfg(has_esys_ac_commands)]
+use std::ptr::null_mut;
+
 impl Context {
-    // Missing function: AC_GetCapability
-    // Missing function: AC_Send
-    // Missing function: Policy_AC_SendSelect
+    /// Get the capabilities of an attached component.
+    ///
+    /// # Arguments
+    /// * `ac` - The [ObjectHandle] of the attached component.
+    /// * `capability` - The [AcCapability] from which to start the listing.
+    /// * `count` - The maximum number of values to return.
+    ///
+    /// # Details
+    /// The `ac` handle is obtained from the TPM handle of the attached component
+    /// using [Context::tr_from_tpm_public].
+    ///
+    /// Only available when built with the `generate-bindings` feature against
+    /// the 4.0.0 version of the tpm2-tss or a later one.
+    ///
+    /// # Returns
+    /// A tuple containing the capabilities of the attached component and a flag
+    /// indicating whether there are more values available.
+    #[cfg(has_esys_ac_commands)]
+    pub fn ac_get_capability(
+        &mut self,
+        ac: ObjectHandle,
+        capability: AcCapability,
+        count: u32,
+    ) -> Result<(AcCapabilityList, bool)> {
+        let mut capability_data_ptr = null_mut();
+        let mut more_data = YesNo::No.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_AC_GetCapability(
+                    self.mut_context(),
+                    ac.into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    capability.into(),
+                    count,
+                    &mut more_data,
+                    &mut capability_data_ptr,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when getting attached component capabilities: {:#010X}",
+                    ret
+                );
+            },
+        )?;
+
+        Ok((
+            AcCapabilityList::try_from(Context::ffi_data_to_owned(capability_data_ptr))?,
+            YesNo::try_from(more_data)?.into(),
+        ))
+    }
+
+    /// Sends an object to an attached component.
+    ///
+    /// # Arguments
+    /// * `send_object` - The [ObjectHandle] of the object to send.
+    /// * `auth_handle` - The [NvAuth] authorization for the sending.
+    /// * `ac` - The [ObjectHandle] of the attached component.
+    /// * `ac_data_in` - Optional non sensitive data sent along with the object.
+    ///
+    /// # Details
+    /// Both `send_object` and `auth_handle` need to be authorized, so the sessions
+    /// that are set on the context need to include two sessions that are able to
+    /// authorize them. The attached component that is allowed to receive the object
+    /// can be restricted using [Context::policy_ac_send_select].
+    ///
+    /// Only available when built with the `generate-bindings` feature against
+    /// the 4.0.0 version of the tpm2-tss or a later one.
+    ///
+    /// # Returns
+    /// The response of the attached component.
+    #[cfg(has_esys_ac_commands)]
+    pub fn ac_send(
+        &mut self,
+        send_object: ObjectHandle,
+        auth_handle: NvAuth,
+        ac: ObjectHandle,
+        ac_data_in: MaxBuffer,
+    ) -> Result<AcOutput> {
+        let mut ac_data_out_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_AC_Send(
+                    self.mut_context(),
+                    send_object.into(),
+                    AuthHandle::from(auth_handle).into(),
+                    ac.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &ac_data_in.into(),
+                    &mut ac_data_out_ptr,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when sending object to attached component: {:#010X}",
+                    ret
+                );
+            },
+        )?;
+
+        AcOutput::try_from(Context::ffi_data_to_owned(ac_data_out_ptr))
+    }
+
+    /// Cause conditional gating of a policy based on the attached component
+    /// an object is sent to.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [PolicySession] that is extended.
+    /// * `object_name` - The [Name] of the object being sent.
+    /// * `auth_handle_name` - The [Name] of the authorization handle used
+    ///                        when sending the object.
+    /// * `ac_name` - The [Name] of the attached component.
+    /// * `include_object` - Whether `object_name` is included in the policy digest.
+    ///
+    /// # Details
+    /// The TPM will ensure that the current policy can only be used to authorize
+    /// [Context::ac_send] when the object is sent to the selected attached component.
+    ///
+    /// Only available when built with the `generate-bindings` feature against
+    /// the 4.0.0 version of the tpm2-tss or a later one.
+    #[cfg(has_esys_ac_commands)]
+    pub fn policy_ac_send_select(
+        &mut self,
+        policy_session: PolicySession,
+        object_name: Name,
+        auth_handle_name: Name,
+        ac_name: Name,
+        include_object: bool,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Policy_AC_SendSelect(
+                    self.mut_context(),
+                    SessionHandle::from(policy_session).into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &object_name.into(),
+                    &auth_handle_name.into(),
+                    &ac_name.into(),
+                    YesNo::from(include_object).into(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when computing policy attached component send select: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
 }
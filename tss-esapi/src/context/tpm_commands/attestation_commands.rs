@@ -1,19 +1,26 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    handles::{KeyHandle, ObjectHandle},
+    handles::{KeyHandle, ObjectHandle, SessionHandle},
+    interface_types::{reserved_handles::Endorsement, session_handles::AuthSession},
     structures::{
         Attest, AttestBuffer, CreationTicket, Data, Digest, PcrSelectionList, Signature,
         SignatureScheme,
     },
-    tss2_esys::{Esys_Certify, Esys_Quote},
-    Context, Result, ReturnCode,
+    tss2_esys::{
+        Esys_Certify, Esys_GetCommandAuditDigest, Esys_GetSessionAuditDigest, Esys_GetTime,
+        Esys_Quote,
+    },
+    Context, Error, Result, ReturnCode, WrapperErrorKind,
 };
 use log::error;
 use std::convert::TryFrom;
 use std::ptr::null_mut;
 use tss_esapi_sys::Esys_CertifyCreation;
 
+#[cfg(has_esys_certify_x509)]
+use crate::{structures::MaxBuffer, tss2_esys::Esys_CertifyX509};
+
 impl Context {
     /// Prove that an object is loaded in the TPM
     ///
@@ -324,8 +331,336 @@ impl Context {
         ))
     }
 
-    // Missing function: GetSessionAuditDigest
-    // Missing function: GestCommandAuditDigest
-    // Missing function: GetTime
-    // Missing function: CertifyX509
+    /// Gets the audit digest of a session, signed by the TPM.
+    ///
+    /// # Arguments
+    /// * `privacy_admin` - The [Endorsement] hierarchy, used to authorize the command.
+    /// * `signing_key_handle` - Handle of the key used to sign the audit information.
+    /// * `audit_session` - The HMAC or policy session whose audit digest is reported.
+    /// * `qualifying_data` - Data provided by the caller that is included in the audit information,
+    ///                       for example a nonce to ensure freshness.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    ///
+    /// # Details
+    /// While a session has the audit attribute set, the TPM extends the session
+    /// audit digest with the cpHash and rpHash of every command that is executed
+    /// using the session. This command returns that digest in an attestation
+    /// structure, which allows a verifier to check which commands were executed
+    /// in the session. Setting the audit attribute with the audit reset attribute
+    /// starts a new digest.
+    ///
+    /// If `signing_key_handle` has the Restricted attribute set to `true` then `signing_scheme` must be
+    /// [SignatureScheme::Null].
+    ///
+    /// This command requires authorization for both `privacy_admin` and `signing_key_handle`
+    /// so two authorization sessions needs to be set.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `audit_info` - Attestation data containing the session audit digest,
+    ///                  see [AttestInfo::SessionAudit](crate::structures::AttestInfo::SessionAudit).
+    /// * `signature` - Signature of the attestation data.
+    ///
+    /// # Errors
+    /// * if the `audit_session` is a password session, an `InvalidParam` wrapper error is returned.
+    /// * if the qualifying data provided is too long, a `WrongParamSize` wrapper error will be returned
+    pub fn get_session_audit_digest(
+        &mut self,
+        privacy_admin: Endorsement,
+        signing_key_handle: KeyHandle,
+        audit_session: AuthSession,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        if audit_session == AuthSession::Password {
+            error!("A password session cannot be an audit session");
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        let mut audit_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_GetSessionAuditDigest(
+                    self.mut_context(),
+                    ObjectHandle::from(privacy_admin).into(),
+                    signing_key_handle.into(),
+                    SessionHandle::from(audit_session).into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    &mut audit_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in getting session audit digest: {:#010X}", ret);
+            },
+        )?;
+
+        let audit_info = Context::ffi_data_to_owned(audit_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(audit_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
+
+    /// Gets the command audit digest of the TPM, signed by the TPM.
+    ///
+    /// # Arguments
+    /// * `privacy_handle` - The [Endorsement] hierarchy, used to authorize the command.
+    /// * `signing_key_handle` - Handle of the key used to sign the audit information.
+    /// * `qualifying_data` - Data provided by the caller that is included in the audit information,
+    ///                       for example a nonce to ensure freshness.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    ///
+    /// # Details
+    /// The TPM extends the command audit digest with the cpHash and rpHash of
+    /// every executed command whose command code is in the list of audited
+    /// commands, see [Self::set_command_code_audit_status()]. This command returns
+    /// the digest together with a digest of the list of audited command codes.
+    /// When this command completes successfully the audit digest is cleared.
+    ///
+    /// If `signing_key_handle` has the Restricted attribute set to `true` then `signing_scheme` must be
+    /// [SignatureScheme::Null].
+    ///
+    /// This command requires authorization for both `privacy_handle` and `signing_key_handle`
+    /// so two authorization sessions needs to be set.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `audit_info` - Attestation data containing the command audit digest,
+    ///                  see [AttestInfo::CommandAudit](crate::structures::AttestInfo::CommandAudit).
+    /// * `signature` - Signature of the attestation data.
+    ///
+    /// # Errors
+    /// * if the qualifying data provided is too long, a `WrongParamSize` wrapper error will be returned
+    pub fn get_command_audit_digest(
+        &mut self,
+        privacy_handle: Endorsement,
+        signing_key_handle: KeyHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut audit_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_GetCommandAuditDigest(
+                    self.mut_context(),
+                    ObjectHandle::from(privacy_handle).into(),
+                    signing_key_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    &mut audit_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in getting command audit digest: {:#010X}", ret);
+            },
+        )?;
+
+        let audit_info = Context::ffi_data_to_owned(audit_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(audit_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
+
+    /// Gets the current time and clock values of the TPM, signed by the TPM.
+    ///
+    /// # Arguments
+    /// * `privacy_admin` - The [Endorsement] hierarchy, used to authorize the command.
+    /// * `signing_key_handle` - Handle of the key used to sign the time information.
+    /// * `qualifying_data` - Data provided by the caller that is included in the time information,
+    ///                       for example a nonce to ensure freshness.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    ///
+    /// # Details
+    /// The attestation data contains the time since the last TPM reset or restart,
+    /// the clock value together with the reset and restart counts and the firmware
+    /// version of the TPM. Unlike the values returned by [Self::read_clock()] these
+    /// values are signed, so they can be used to prove the state of the clock to a
+    /// remote party.
+    ///
+    /// If `signing_key_handle` has the Restricted attribute set to `true` then `signing_scheme` must be
+    /// [SignatureScheme::Null].
+    ///
+    /// This command requires authorization for both `privacy_admin` and `signing_key_handle`
+    /// so two authorization sessions needs to be set.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `time_info` - Attestation data containing the time information,
+    ///                 see [AttestInfo::Time](crate::structures::AttestInfo::Time).
+    /// * `signature` - Signature of the attestation data.
+    ///
+    /// # Errors
+    /// * if the qualifying data provided is too long, a `WrongParamSize` wrapper error will be returned
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// # use std::convert::TryFrom;
+    /// # use tss_esapi::{
+    /// #     interface_types::{
+    /// #         algorithm::{HashingAlgorithm, RsaSchemeAlgorithm},
+    /// #         key_bits::RsaKeyBits,
+    /// #         reserved_handles::Hierarchy,
+    /// #     },
+    /// #     structures::{RsaExponent, RsaScheme},
+    /// #     utils::create_unrestricted_signing_rsa_public,
+    /// # };
+    /// use tss_esapi::{
+    ///     interface_types::{reserved_handles::Endorsement, session_handles::AuthSession},
+    ///     structures::{AttestInfo, Data, SignatureScheme},
+    /// };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// # let signing_key_pub = create_unrestricted_signing_rsa_public(
+    /// #         RsaScheme::create(RsaSchemeAlgorithm::RsaSsa, Some(HashingAlgorithm::Sha256))
+    /// #         .expect("Failed to create RSA scheme"),
+    /// #     RsaKeyBits::Rsa2048,
+    /// #     RsaExponent::default(),
+    /// # )
+    /// # .expect("Failed to create an unrestricted signing rsa public structure");
+    /// # let sign_key_handle = context
+    /// #     .execute_with_nullauth_session(|ctx| {
+    /// #         ctx.create_primary(Hierarchy::Owner, signing_key_pub, None, None, None, None)
+    /// #     })
+    /// #     .unwrap()
+    /// #     .key_handle;
+    /// let (attest, signature) = context
+    ///     .execute_with_sessions(
+    ///         (
+    ///             Some(AuthSession::Password),
+    ///             Some(AuthSession::Password),
+    ///             None,
+    ///         ),
+    ///         |ctx| {
+    ///             ctx.get_time(
+    ///                 Endorsement::Endorsement,
+    ///                 sign_key_handle,
+    ///                 Data::try_from(vec![0xff; 16]).unwrap(),
+    ///                 SignatureScheme::Null,
+    ///             )
+    ///         },
+    ///     )
+    ///     .expect("Failed to get time");
+    /// if let AttestInfo::Time { info } = attest.attested() {
+    ///     println!("Clock: {}", info.time_info().clock_info().clock());
+    /// }
+    /// ```
+    pub fn get_time(
+        &mut self,
+        privacy_admin: Endorsement,
+        signing_key_handle: KeyHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut time_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_GetTime(
+                    self.mut_context(),
+                    ObjectHandle::from(privacy_admin).into(),
+                    signing_key_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    &mut time_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in getting time: {:#010X}", ret);
+            },
+        )?;
+
+        let time_info = Context::ffi_data_to_owned(time_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(time_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
+
+    /// Have the TPM sign a partial X.509 certificate for an object
+    ///
+    /// # Arguments
+    /// * `object_handle` - Handle of the object to be certified
+    /// * `signing_key_handle` - Handle of the key used to sign the certificate
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    /// * `partial_certificate` - A DER encoded partial TBSCertificate.
+    ///
+    /// The `partial_certificate` is a DER encoded SEQUENCE containing the issuer, validity,
+    /// subject and extensions of the certificate, optionally preceded by the signature
+    /// algorithm identifier. The TPM adds the remaining fields of the TBSCertificate
+    /// (version, serial number, signature algorithm identifier if it was not provided and
+    /// the subject public key info) and signs the complete TBSCertificate.
+    ///
+    /// The `signing_key_handle` must be an unrestricted key usable for signing.
+    ///
+    /// This command requires authorization for both `object_handle` and `signing_key_handle`
+    /// so two authorization sessions needs to be set.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `added_to_certificate` - A DER encoded SEQUENCE containing the fields added by the TPM.
+    /// * `tbs_digest` - Digest of the complete TBSCertificate.
+    /// * `signature` - Signature of the TBSCertificate.
+    ///
+    /// The `abstraction::x509` module provides a way of producing a complete certificate.
+    #[cfg(has_esys_certify_x509)]
+    pub fn certify_x509(
+        &mut self,
+        object_handle: ObjectHandle,
+        signing_key_handle: KeyHandle,
+        signing_scheme: SignatureScheme,
+        partial_certificate: MaxBuffer,
+    ) -> Result<(MaxBuffer, Digest, Signature)> {
+        let mut added_to_certificate_ptr = null_mut();
+        let mut tbs_digest_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_CertifyX509(
+                    self.mut_context(),
+                    object_handle.into(),
+                    signing_key_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &Data::default().into(),
+                    &signing_scheme.into(),
+                    &partial_certificate.into(),
+                    &mut added_to_certificate_ptr,
+                    &mut tbs_digest_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in certifying X.509: {:#010X}", ret);
+            },
+        )?;
+
+        Ok((
+            MaxBuffer::try_from(Context::ffi_data_to_owned(added_to_certificate_ptr))?,
+            Digest::try_from(Context::ffi_data_to_owned(tbs_digest_ptr))?,
+            Signature::try_from(Context::ffi_data_to_owned(signature_ptr))?,
+        ))
+    }
 }
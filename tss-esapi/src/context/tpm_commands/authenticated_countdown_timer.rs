@@ -2,6 +2,49 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::Context;
 
+// The ACT_SetTimeout command was first added to
+// the ESAPI in the 3.0.0 version of the tpm2-tss.
+#[cfg(has_esys_act)]
+use crate::{
+    handles::AuthHandle, interface_types::reserved_handles::Act, tss2_esys::Esys_ACT_SetTimeout,
+    Result, ReturnCode,
+};
+#[cfg(has_esys_act)]
+use log::error;
+
 impl Context {
-    // Missing function: ACT_SetTimeout
+    /// Sets the timeout of an authenticated countdown timer.
+    ///
+    /// # Arguments
+    /// * `act_handle` - The [Act] to set the timeout for.
+    /// * `start_timeout` - The number of seconds before the ACT is signaled.
+    ///
+    /// # Details
+    /// Once the timeout has been set the ACT counts down and, if the timeout is
+    /// not set again before it expires, the ACT is signaled. Setting a timeout
+    /// of zero while the ACT is signaled clears the signaled state.
+    ///
+    /// The state of the ACTs can be read using [Context::get_capability] with
+    /// [crate::constants::CapabilityType::Act].
+    ///
+    /// This command requires authorization for the ACT so an authorization
+    /// session needs to be set.
+    #[cfg(has_esys_act)]
+    pub fn act_set_timeout(&mut self, act_handle: Act, start_timeout: u32) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ACT_SetTimeout(
+                    self.mut_context(),
+                    AuthHandle::from(act_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    start_timeout,
+                )
+            },
+            |ret| {
+                error!("Error when setting ACT timeout: {:#010X}", ret);
+            },
+        )
+    }
 }
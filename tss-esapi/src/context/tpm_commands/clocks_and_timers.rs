@@ -1,9 +1,137 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    constants::ClockAdjust,
+    handles::AuthHandle,
+    interface_types::reserved_handles::Provision,
+    structures::TimeInfo,
+    tss2_esys::{Esys_ClockRateAdjust, Esys_ClockSet, Esys_ReadClock},
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: ReadClock
-    // Missing function: ClockSet
-    // Missing function: ClockRateAdjust
+    /// Reads the current time and clock values of the TPM.
+    ///
+    /// # Details
+    /// The returned [TimeInfo] contains the time since the last TPM reset or
+    /// restart as well as the [ClockInfo](crate::structures::ClockInfo), which
+    /// holds the clock value together with the reset and restart counts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, tcti_ldr::TctiNameConf};
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let time_info = context.read_clock().expect("Call to read_clock failed");
+    /// println!(
+    ///     "Clock: {}, reset count: {}, restart count: {}",
+    ///     time_info.clock_info().clock(),
+    ///     time_info.clock_info().reset_count(),
+    ///     time_info.clock_info().restart_count(),
+    /// );
+    /// ```
+    pub fn read_clock(&mut self) -> Result<TimeInfo> {
+        let mut current_time_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ReadClock(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &mut current_time_ptr,
+                )
+            },
+            |ret| {
+                error!("Error failed to read clock: {:#010X}", ret);
+            },
+        )?;
+        TimeInfo::try_from(Context::ffi_data_to_owned(current_time_ptr))
+    }
+
+    /// Advances the value of the TPM clock.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] hierarchy that authorizes the command.
+    /// * `new_time` - The new clock value in milliseconds.
+    ///
+    /// # Details
+    /// The TPM will only accept a `new_time` that is greater than or equal to
+    /// the current clock value, the clock can not be set backwards.
+    ///
+    /// This command requires authorization for the `auth` hierarchy so an
+    /// authorization session needs to be set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, tcti_ldr::TctiNameConf,
+    /// #     interface_types::{reserved_handles::Provision, session_handles::AuthSession},
+    /// # };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let clock = context
+    ///     .read_clock()
+    ///     .expect("Call to read_clock failed")
+    ///     .clock_info()
+    ///     .clock();
+    /// context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     ctx.clock_set(Provision::Owner, clock + 1000)
+    ///         .expect("Call to clock_set failed");
+    /// });
+    /// ```
+    pub fn clock_set(&mut self, auth: Provision, new_time: u64) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ClockSet(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    new_time,
+                )
+            },
+            |ret| {
+                error!("Error failed to set clock: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Adjusts the rate at which the TPM clock is updated.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] hierarchy that authorizes the command.
+    /// * `rate_adjust` - The [ClockAdjust] that is going to be applied.
+    ///
+    /// # Details
+    /// This command requires authorization for the `auth` hierarchy so an
+    /// authorization session needs to be set.
+    pub fn clock_rate_adjust(&mut self, auth: Provision, rate_adjust: ClockAdjust) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ClockRateAdjust(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    rate_adjust.into(),
+                )
+            },
+            |ret| {
+                error!("Error failed to adjust clock rate: {:#010X}", ret);
+            },
+        )
+    }
 }
@@ -1,7 +1,97 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::AuthHandle,
+    interface_types::{algorithm::HashingAlgorithm, reserved_handles::Provision},
+    structures::CommandCodeList,
+    tss2_esys::Esys_SetCommandCodeAuditStatus,
+    Context, Result, ReturnCode,
+};
+use log::error;
 
 impl Context {
-    // Missing function: SetCommandCodeAuditStatus
+    /// Changes the audit status of commands.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] hierarchy that authorizes the command.
+    /// * `audit_alg` - The [HashingAlgorithm] that is going to be used for the
+    ///                 command audit digest. If [HashingAlgorithm::Null] is used
+    ///                 then the algorithm is not changed.
+    /// * `set_list` - The list of commands that will be added to the list of
+    ///                audited commands.
+    /// * `clear_list` - The list of commands that will no longer be audited.
+    ///
+    /// # Details
+    /// The hashing algorithm can only be changed when both `set_list` and
+    /// `clear_list` are empty, a change of the hashing algorithm will also
+    /// clear the audit digest.
+    ///
+    /// This command requires authorization for the `auth` hierarchy so an
+    /// authorization session needs to be set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, tcti_ldr::TctiNameConf,
+    /// #     constants::CommandCode,
+    /// #     interface_types::{
+    /// #         algorithm::HashingAlgorithm, reserved_handles::Provision,
+    /// #         session_handles::AuthSession,
+    /// #     },
+    /// #     structures::CommandCodeList,
+    /// # };
+    /// # use std::convert::TryFrom;
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     // Select the hashing algorithm used for the audit digest.
+    ///     ctx.set_command_code_audit_status(
+    ///         Provision::Owner,
+    ///         HashingAlgorithm::Sha256,
+    ///         CommandCodeList::new(),
+    ///         CommandCodeList::new(),
+    ///     )
+    ///     .expect("Call to set_command_code_audit_status failed");
+    ///     // Start auditing the GetRandom command.
+    ///     ctx.set_command_code_audit_status(
+    ///         Provision::Owner,
+    ///         HashingAlgorithm::Null,
+    ///         CommandCodeList::try_from(vec![CommandCode::GetRandom])
+    ///             .expect("Failed to create CommandCodeList"),
+    ///         CommandCodeList::new(),
+    ///     )
+    ///     .expect("Call to set_command_code_audit_status failed");
+    /// });
+    /// ```
+    pub fn set_command_code_audit_status(
+        &mut self,
+        auth: Provision,
+        audit_alg: HashingAlgorithm,
+        set_list: CommandCodeList,
+        clear_list: CommandCodeList,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SetCommandCodeAuditStatus(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    audit_alg.into(),
+                    &set_list.into(),
+                    &clear_list.into(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error failed to set command code audit status: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
 }
@@ -1,8 +1,123 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::ObjectHandle,
+    interface_types::reserved_handles::Lockout,
+    tss2_esys::{Esys_DictionaryAttackLockReset, Esys_DictionaryAttackParameters},
+    Context, Result, ReturnCode,
+};
+use log::error;
 
 impl Context {
-    // Missing function: DictionaryAttackLockReset
-    // Missing function: DictionaryAttackParameters
+    /// Cancels the effect of a TPM lockout due to a number of successive
+    /// authorization failures.
+    ///
+    /// # Arguments
+    /// * `lock_handle` - The [Lockout] authorization.
+    ///
+    /// # Details
+    /// If this command is successful, the failure counter is set to zero
+    /// and the TPM leaves the lockout mode. The command requires
+    /// authorization using the lockout authorization.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf,
+    /// #     interface_types::{reserved_handles::Lockout, session_handles::AuthSession},
+    /// # };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     ctx.dictionary_attack_lock_reset(Lockout::Lockout)
+    ///         .expect("Call to dictionary_attack_lock_reset failed");
+    /// });
+    /// ```
+    pub fn dictionary_attack_lock_reset(&mut self, lock_handle: Lockout) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_DictionaryAttackLockReset(
+                    self.mut_context(),
+                    ObjectHandle::from(lock_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when resetting dictionary attack lockout: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
+
+    /// Changes the parameters of the dictionary attack protection.
+    ///
+    /// # Arguments
+    /// * `lock_handle` - The [Lockout] authorization.
+    /// * `new_max_tries` - The number of authorization failures before the TPM
+    ///                     enters the lockout mode.
+    /// * `new_recovery_time` - The time in seconds after which one authorization
+    ///                         failure is forgotten. If zero then the dictionary
+    ///                         attack protection is disabled.
+    /// * `lockout_recovery` - The time in seconds after a failed authorization
+    ///                        using the lockout authorization before the lockout
+    ///                        authorization can be used again. If zero then a
+    ///                        TPM reset is required.
+    ///
+    /// # Details
+    /// The current values can be read using [Context::get_tpm_property] with the
+    /// [crate::constants::PropertyTag::MaxAuthFail],
+    /// [crate::constants::PropertyTag::LockoutInterval] and
+    /// [crate::constants::PropertyTag::LockoutRecovery] properties.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf,
+    /// #     interface_types::{reserved_handles::Lockout, session_handles::AuthSession},
+    /// # };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     ctx.dictionary_attack_parameters(Lockout::Lockout, 3, 1000, 1000)
+    ///         .expect("Call to dictionary_attack_parameters failed");
+    /// });
+    /// ```
+    pub fn dictionary_attack_parameters(
+        &mut self,
+        lock_handle: Lockout,
+        new_max_tries: u32,
+        new_recovery_time: u32,
+        lockout_recovery: u32,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_DictionaryAttackParameters(
+                    self.mut_context(),
+                    ObjectHandle::from(lock_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    new_max_tries,
+                    new_recovery_time,
+                    lockout_recovery,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when setting dictionary attack parameters: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
 }
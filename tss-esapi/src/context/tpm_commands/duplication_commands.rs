@@ -3,8 +3,8 @@
 use crate::Context;
 use crate::{
     handles::ObjectHandle,
-    structures::{Data, EncryptedSecret, Private, Public, SymmetricDefinitionObject},
-    tss2_esys::{Esys_Duplicate, Esys_Import},
+    structures::{Data, EncryptedSecret, Name, Private, Public, SymmetricDefinitionObject},
+    tss2_esys::{Esys_Duplicate, Esys_Import, Esys_Rewrap},
     Result, ReturnCode,
 };
 use log::error;
@@ -333,7 +333,69 @@ impl Context {
         ))
     }
 
-    // Missing function: Rewrap
+    /// Rewrap a duplicated object so that it is protected by a different new parent.
+    ///
+    /// # Details
+    /// This command allows the TPM to serve in the role of a Duplication Authority.
+    /// The outer wrapper of a duplicated object, that was created for `old_parent`,
+    /// is replaced by an outer wrapper for `new_parent`. The inner wrapper, if any,
+    /// is left as it is so the sensitive area of the object is not exposed.
+    ///
+    /// Only the public area of `new_parent` is required to be loaded.
+    ///
+    /// # Arguments
+    /// * `old_parent` - An [ObjectHandle] of the parent of the object. This is the new parent
+    ///                  that was used when the object was duplicated.
+    /// * `new_parent` - An [ObjectHandle] of the new parent of the object.
+    /// * `in_duplicate` - An object protected by the old parent, as returned
+    ///                    from [Context::duplicate].
+    /// * `name` - The [Name] of the object being rewrapped.
+    /// * `in_sym_seed` - The seed for the symmetric key and HMAC key, as returned
+    ///                   from [Context::duplicate].
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `out_duplicate` - The object encrypted using the symmetric key derived from `out_sym_seed`.
+    /// * `out_sym_seed` - Seed for a symmetric key protected by the new parent.
+    ///
+    /// The returned values can be used with [Context::import] under the `new_parent`, using the
+    /// same inner wrapper encryption key and symmetric algorithm as the original duplication.
+    pub fn rewrap(
+        &mut self,
+        old_parent: ObjectHandle,
+        new_parent: ObjectHandle,
+        in_duplicate: Private,
+        name: Name,
+        in_sym_seed: EncryptedSecret,
+    ) -> Result<(Private, EncryptedSecret)> {
+        let mut out_duplicate_ptr = null_mut();
+        let mut out_sym_seed_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Rewrap(
+                    self.mut_context(),
+                    old_parent.into(),
+                    new_parent.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &in_duplicate.into(),
+                    &name.into(),
+                    &in_sym_seed.into(),
+                    &mut out_duplicate_ptr,
+                    &mut out_sym_seed_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when performing rewrap: {:#010X}", ret);
+            },
+        )?;
+
+        Ok((
+            Private::try_from(Context::ffi_data_to_owned(out_duplicate_ptr))?,
+            EncryptedSecret::try_from(Context::ffi_data_to_owned(out_sym_seed_ptr))?,
+        ))
+    }
 
     /// Import attaches imported object to a new parent.
     ///
@@ -2,19 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     attributes::LocalityAttributes,
-    constants::CommandCode,
+    constants::{ArithmeticComparison, CommandCode},
     handles::{AuthHandle, NvIndexHandle, ObjectHandle, SessionHandle},
     interface_types::{reserved_handles::NvAuth, session_handles::PolicySession, YesNo},
     structures::{
-        AuthTicket, Digest, DigestList, Name, Nonce, PcrSelectionList, Signature, Timeout,
+        AuthTicket, Digest, DigestList, Name, Nonce, Operand, PcrSelectionList, Signature, Timeout,
         VerifiedTicket,
     },
     tss2_esys::{
         Esys_PolicyAuthValue, Esys_PolicyAuthorize, Esys_PolicyAuthorizeNV, Esys_PolicyCommandCode,
-        Esys_PolicyCpHash, Esys_PolicyDuplicationSelect, Esys_PolicyGetDigest, Esys_PolicyLocality,
-        Esys_PolicyNameHash, Esys_PolicyNvWritten, Esys_PolicyOR, Esys_PolicyPCR,
-        Esys_PolicyPassword, Esys_PolicyPhysicalPresence, Esys_PolicySecret, Esys_PolicySigned,
-        Esys_PolicyTemplate,
+        Esys_PolicyCounterTimer, Esys_PolicyCpHash, Esys_PolicyDuplicationSelect,
+        Esys_PolicyGetDigest, Esys_PolicyLocality, Esys_PolicyNV, Esys_PolicyNameHash,
+        Esys_PolicyNvWritten, Esys_PolicyOR, Esys_PolicyPCR, Esys_PolicyPassword,
+        Esys_PolicyPhysicalPresence, Esys_PolicySecret, Esys_PolicySigned, Esys_PolicyTemplate,
+        Esys_PolicyTicket,
     },
     Context, Error, Result, ReturnCode, WrapperErrorKind as ErrorKind,
 };
@@ -25,6 +26,11 @@ use std::time::Duration;
 
 impl Context {
     /// Cause the policy to include a signed authorization
+    ///
+    /// # Details
+    /// If `request_ticket` is set, the TPM returns a ticket that can be used
+    /// with [Context::policy_ticket] until the authorization expires, which
+    /// requires an `expiration` to be provided.
     #[allow(clippy::too_many_arguments)]
     pub fn policy_signed(
         &mut self,
@@ -34,6 +40,7 @@ impl Context {
         cp_hash_a: Digest,
         policy_ref: Nonce,
         expiration: Option<Duration>,
+        request_ticket: bool,
         signature: Signature,
     ) -> Result<(Timeout, AuthTicket)> {
         let mut out_timeout_ptr = null_mut();
@@ -50,10 +57,7 @@ impl Context {
                     &nonce_tpm.into(),
                     &cp_hash_a.into(),
                     &policy_ref.into(),
-                    i32::try_from(expiration.map_or(0, |v| v.as_secs())).map_err(|e| {
-                        error!("Unable to convert duration to i32: {}", e);
-                        Error::local_error(ErrorKind::InvalidParam)
-                    })?,
+                    expiration_value(expiration, request_ticket)?,
                     &signature.try_into()?,
                     &mut out_timeout_ptr,
                     &mut out_policy_ticket_ptr,
@@ -70,6 +74,12 @@ impl Context {
     }
 
     /// Cause the policy to require a secret in authValue
+    ///
+    /// # Details
+    /// If `request_ticket` is set, the TPM returns a ticket that can be used
+    /// with [Context::policy_ticket] until the authorization expires, which
+    /// requires an `expiration` to be provided.
+    #[allow(clippy::too_many_arguments)]
     pub fn policy_secret(
         &mut self,
         policy_session: PolicySession,
@@ -78,6 +88,7 @@ impl Context {
         cp_hash_a: Digest,
         policy_ref: Nonce,
         expiration: Option<Duration>,
+        request_ticket: bool,
     ) -> Result<(Timeout, AuthTicket)> {
         let mut out_timeout_ptr = null_mut();
         let mut out_policy_ticket_ptr = null_mut();
@@ -93,10 +104,7 @@ impl Context {
                     &nonce_tpm.into(),
                     &cp_hash_a.into(),
                     &policy_ref.into(),
-                    i32::try_from(expiration.map_or(0, |v| v.as_secs())).map_err(|e| {
-                        error!("Unable to convert duration to i32: {}", e);
-                        Error::local_error(ErrorKind::InvalidParam)
-                    })?,
+                    expiration_value(expiration, request_ticket)?,
                     &mut out_timeout_ptr,
                     &mut out_policy_ticket_ptr,
                 )
@@ -111,7 +119,51 @@ impl Context {
         ))
     }
 
-    // Missing function: PolicyTicket
+    /// Cause the policy to include an authorization that was previously
+    /// obtained using [Context::policy_signed] or [Context::policy_secret].
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `timeout` - The [Timeout] that was returned together with the ticket.
+    /// * `cp_hash_a` - The command parameter hash that was used when the ticket was created.
+    /// * `policy_ref` - The policy qualifier that was used when the ticket was created.
+    /// * `auth_name` - The [Name] of the object that provided the authorization.
+    /// * `ticket` - The [AuthTicket] that is going to be used.
+    ///
+    /// # Details
+    /// This makes it possible to reuse an authorization, until it expires, in
+    /// policy sessions other than the one in which the authorization was given.
+    /// The TPM only produces a ticket that can be used with this command when the
+    /// ticket was requested, any other ticket will be rejected by the TPM.
+    pub fn policy_ticket(
+        &mut self,
+        policy_session: PolicySession,
+        timeout: Timeout,
+        cp_hash_a: Digest,
+        policy_ref: Nonce,
+        auth_name: &Name,
+        ticket: AuthTicket,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicyTicket(
+                    self.mut_context(),
+                    SessionHandle::from(policy_session).into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &timeout.into(),
+                    &cp_hash_a.into(),
+                    &policy_ref.into(),
+                    auth_name.as_ref(),
+                    &ticket.try_into()?,
+                )
+            },
+            |ret| {
+                error!("Error when sending policy ticket: {:#010X}", ret);
+            },
+        )
+    }
 
     /// Cause conditional gating of a policy based on an OR'd condition.
     ///
@@ -216,8 +268,93 @@ impl Context {
         )
     }
 
-    // Missing function: PolicyNV
-    // Missing function: PolicyCounterTimer
+    /// Cause conditional gating of a policy based on the contents of an NV index.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `auth_handle` - The [NvAuth] handle that is used to authorize the read
+    ///                   of the NV index.
+    /// * `nv_index_handle` - The [NvIndexHandle] of the NV index that is going to be read.
+    /// * `operand_b` - The second operand of the comparison.
+    /// * `offset` - The octet offset in the NV index data at which the comparison starts.
+    /// * `operation` - The [ArithmeticComparison] that is going to be performed.
+    ///
+    /// # Details
+    /// The TPM will ensure that the contents of the NV index, starting at `offset`,
+    /// compared with `operand_b` using `operation` is true. When a trial session is used
+    /// the comparison is not performed, but the policy digest is still updated.
+    ///
+    /// This command requires authorization for the `auth_handle` so an authorization
+    /// session needs to be set.
+    pub fn policy_nv(
+        &mut self,
+        policy_session: PolicySession,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        operand_b: Operand,
+        offset: u16,
+        operation: ArithmeticComparison,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicyNV(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    SessionHandle::from(policy_session).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &operand_b.into(),
+                    offset,
+                    operation.into(),
+                )
+            },
+            |ret| {
+                error!("Error when computing policy NV: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Cause conditional gating of a policy based on the contents of the
+    /// TPMS_TIME_INFO structure.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `operand_b` - The second operand of the comparison.
+    /// * `offset` - The octet offset in the marshalled TPMS_TIME_INFO structure
+    ///              at which the comparison starts.
+    /// * `operation` - The [ArithmeticComparison] that is going to be performed.
+    ///
+    /// # Details
+    /// The TPM will ensure that the time and clock values, starting at `offset`,
+    /// compared with `operand_b` using `operation` is true. When a trial session is used
+    /// the comparison is not performed, but the policy digest is still updated.
+    pub fn policy_counter_timer(
+        &mut self,
+        policy_session: PolicySession,
+        operand_b: Operand,
+        offset: u16,
+        operation: ArithmeticComparison,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicyCounterTimer(
+                    self.mut_context(),
+                    SessionHandle::from(policy_session).into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &operand_b.into(),
+                    offset,
+                    operation.into(),
+                )
+            },
+            |ret| {
+                error!("Error when computing policy counter timer: {:#010X}", ret);
+            },
+        )
+    }
 
     /// Cause conditional gating of a policy based on command code of authorized command.
     ///
@@ -727,3 +864,24 @@ impl Context {
         )
     }
 }
+
+/// Converts the expiration of an authorization into the value expected by the TPM,
+/// which is negated when a ticket is requested.
+///
+/// # Errors
+/// * if the expiration is too large, or if a ticket is requested without
+///   an expiration, an `InvalidParam` wrapper error is returned.
+fn expiration_value(expiration: Option<Duration>, request_ticket: bool) -> Result<i32> {
+    let seconds = i32::try_from(expiration.map_or(0, |v| v.as_secs())).map_err(|e| {
+        error!("Unable to convert duration to i32: {}", e);
+        Error::local_error(ErrorKind::InvalidParam)
+    })?;
+    if !request_ticket {
+        return Ok(seconds);
+    }
+    if seconds == 0 {
+        error!("A ticket can only be requested for an authorization that expires");
+        return Err(Error::local_error(ErrorKind::InvalidParam));
+    }
+    Ok(-seconds)
+}
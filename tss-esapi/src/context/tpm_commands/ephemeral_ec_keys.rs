@@ -1,8 +1,144 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::KeyHandle,
+    interface_types::ecc::EccCurve,
+    structures::{CommitResult, EccParameter, EccPoint, SensitiveData},
+    tss2_esys::{Esys_Commit, Esys_EC_Ephemeral, TPM2B_ECC_POINT},
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::ptr::{null, null_mut};
 
 impl Context {
-    // Missing function: Commit
-    // Missing function: EC_Ephemeral
+    /// Performs the first part of an ECC anonymous signing operation.
+    ///
+    /// # Arguments
+    /// * `sign_handle` - A [KeyHandle] of an ECC key using a split signing
+    ///                   scheme (e.g. ECDAA).
+    /// * `p1` - An optional [EccPoint] on the curve of the key.
+    /// * `s2` - Optional octet array used to derive the x coordinate of the
+    ///          base point.
+    /// * `y2` - Optional y coordinate of the base point.
+    ///
+    /// # Details
+    /// The TPM generates an ephemeral value `r` and returns the points
+    /// `K`, `L` and `E` together with the `counter` that identifies the
+    /// ephemeral value. `K` and `L` are only computed when `s2` and `y2`
+    /// are provided and `E` is only computed when `p1` is provided, the
+    /// points that are not computed are returned as `None`.
+    ///
+    /// The `counter` is then used in the scheme of the signing operation
+    /// (e.g. [crate::structures::EcDaaScheme]) that completes the split
+    /// signing operation.
+    ///
+    /// This command requires authorization for the key so an authorization
+    /// session needs to be set.
+    ///
+    /// # Returns
+    /// A [CommitResult] containing `K`, `L`, `E` and the `counter`.
+    pub fn commit(
+        &mut self,
+        sign_handle: KeyHandle,
+        p1: Option<EccPoint>,
+        s2: Option<SensitiveData>,
+        y2: Option<EccParameter>,
+    ) -> Result<CommitResult> {
+        let mut k_ptr = null_mut();
+        let mut l_ptr = null_mut();
+        let mut e_ptr = null_mut();
+        let mut counter = 0;
+        let potential_tpm2b_p1: Option<TPM2B_ECC_POINT> = p1.map(|v| v.into());
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Commit(
+                    self.mut_context(),
+                    sign_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    potential_tpm2b_p1.as_ref().map_or(null(), |v| v),
+                    &s2.unwrap_or_default().into(),
+                    &y2.unwrap_or_default().into(),
+                    &mut k_ptr,
+                    &mut l_ptr,
+                    &mut e_ptr,
+                    &mut counter,
+                )
+            },
+            |ret| {
+                error!("Error when performing commit: {:#010X}", ret);
+            },
+        )?;
+
+        let k = Context::ffi_data_to_owned(k_ptr);
+        let l = Context::ffi_data_to_owned(l_ptr);
+        let e = Context::ffi_data_to_owned(e_ptr);
+        Ok(CommitResult {
+            k: Context::optional_ecc_point(k)?,
+            l: Context::optional_ecc_point(l)?,
+            e: Context::optional_ecc_point(e)?,
+            counter,
+        })
+    }
+
+    /// Converts a point that the TPM may have omitted, indicated
+    /// by an empty TPM2B_ECC_POINT, into an optional [EccPoint].
+    fn optional_ecc_point(point: TPM2B_ECC_POINT) -> Result<Option<EccPoint>> {
+        if point.size == 0 {
+            Ok(None)
+        } else {
+            EccPoint::try_from(point.point).map(Some)
+        }
+    }
+
+    /// Creates an ephemeral key for use in a two-phase key exchange protocol.
+    ///
+    /// # Arguments
+    /// * `curve` - The [EccCurve] for which the ephemeral key is created.
+    ///
+    /// # Details
+    /// The TPM returns the public part of the ephemeral key together with
+    /// the `counter` that identifies the private part. The `counter` is
+    /// then used in [Context::zgen_2phase] in order to complete the key
+    /// exchange.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf, interface_types::ecc::EccCurve};
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let (q, _counter) = context
+    ///     .ec_ephemeral(EccCurve::NistP256)
+    ///     .expect("Call to ec_ephemeral failed");
+    /// assert!(!q.x().is_empty());
+    /// ```
+    pub fn ec_ephemeral(&mut self, curve: EccCurve) -> Result<(EccPoint, u16)> {
+        let mut q_ptr = null_mut();
+        let mut counter = 0;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_EC_Ephemeral(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    curve.into(),
+                    &mut q_ptr,
+                    &mut counter,
+                )
+            },
+            |ret| {
+                error!("Error when creating ephemeral key: {:#010X}", ret);
+            },
+        )?;
+
+        let q = Context::ffi_data_to_owned(q_ptr);
+        Ok((EccPoint::try_from(q.point)?, counter))
+    }
 }
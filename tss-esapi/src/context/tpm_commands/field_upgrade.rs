@@ -1,9 +1,134 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    constants::AlgorithmIdentifier,
+    handles::{AuthHandle, KeyHandle},
+    interface_types::reserved_handles::Platform,
+    structures::{Digest, HashAgile, MaxBuffer, Signature},
+    tss2_esys::{Esys_FieldUpgradeData, Esys_FieldUpgradeStart, Esys_FirmwareRead},
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: FieldUpgradeStart
-    // Missing function: FieldUpgradeData
-    // Missing function: FirmwareRead
+    /// Starts a field upgrade of the TPM firmware.
+    ///
+    /// # Arguments
+    /// * `authorization` - The [Platform] authorization.
+    /// * `key_handle` - The [KeyHandle] of the public key used to verify
+    ///                  the manifest signature.
+    /// * `fu_digest` - The digest of the first block of the field upgrade data.
+    /// * `manifest_signature` - The vendor signature over `fu_digest`.
+    ///
+    /// # Details
+    /// The format of the upgrade data and of the manifest is vendor specific.
+    /// After the upgrade has been started the data needs to be provided using
+    /// [Context::field_upgrade_data].
+    ///
+    /// The platform authorization needs to be provided so an authorization
+    /// session needs to be set.
+    pub fn field_upgrade_start(
+        &mut self,
+        authorization: Platform,
+        key_handle: KeyHandle,
+        fu_digest: Digest,
+        manifest_signature: Signature,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_FieldUpgradeStart(
+                    self.mut_context(),
+                    AuthHandle::from(authorization).into(),
+                    key_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &fu_digest.into(),
+                    &manifest_signature.try_into()?,
+                )
+            },
+            |ret| {
+                error!("Error when starting field upgrade: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Provides a block of field upgrade data to the TPM.
+    ///
+    /// # Arguments
+    /// * `fu_data` - A block of the field upgrade data.
+    ///
+    /// # Details
+    /// The blocks of the upgrade data need to be provided in order, see
+    /// [crate::abstraction::field_upgrade::field_upgrade_data] for a helper
+    /// that streams the data from a reader.
+    ///
+    /// # Returns
+    /// A tuple containing the digest of the next block, which is `None` when
+    /// the TPM does not expect any more data, and the digest of the first block
+    /// of the upgrade sequence.
+    pub fn field_upgrade_data(
+        &mut self,
+        fu_data: MaxBuffer,
+    ) -> Result<(Option<HashAgile>, HashAgile)> {
+        let mut next_digest_ptr = null_mut();
+        let mut first_digest_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_FieldUpgradeData(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &fu_data.into(),
+                    &mut next_digest_ptr,
+                    &mut first_digest_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when providing field upgrade data: {:#010X}", ret);
+            },
+        )?;
+
+        let next_digest = Context::ffi_data_to_owned(next_digest_ptr);
+        let first_digest = Context::ffi_data_to_owned(first_digest_ptr);
+        Ok((
+            if next_digest.hashAlg == AlgorithmIdentifier::Null.into() {
+                None
+            } else {
+                Some(HashAgile::try_from(next_digest)?)
+            },
+            HashAgile::try_from(first_digest)?,
+        ))
+    }
+
+    /// Reads a block of the TPM firmware.
+    ///
+    /// # Arguments
+    /// * `sequence_number` - The number of the block to read, starting at zero.
+    ///
+    /// # Details
+    /// The format of the returned data is vendor specific. The TPM returns
+    /// an error when `sequence_number` is past the last block.
+    pub fn firmware_read(&mut self, sequence_number: u32) -> Result<MaxBuffer> {
+        let mut fu_data_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_FirmwareRead(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    sequence_number,
+                    &mut fu_data_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when reading firmware: {:#010X}", ret);
+            },
+        )?;
+        MaxBuffer::try_from(Context::ffi_data_to_owned(fu_data_ptr))
+    }
 }
@@ -1,12 +1,376 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    context::handle_manager::HandleDropAction,
+    handles::{ObjectHandle, PcrHandle, SequenceHandle, TpmHandle},
+    interface_types::{algorithm::HashingAlgorithm, reserved_handles::Hierarchy},
+    structures::{Auth, Digest, DigestValues, HashcheckTicket, MaxBuffer},
+    tss2_esys::{
+        Esys_EventSequenceComplete, Esys_HMAC_Start, Esys_HashSequenceStart, Esys_SequenceComplete,
+        Esys_SequenceUpdate,
+    },
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
+
+// The MAC_Start command was first added to
+// the ESAPI in the 4.0.0 version of the tpm2-tss.
+#[cfg(has_esys_mac)]
+use crate::{interface_types::algorithm::MacSchemeAlgorithm, tss2_esys::Esys_MAC_Start};
 
 impl Context {
-    // Missing function: HMAC_Start
-    // Missing function: MAC_Start
-    // Missing function: HashSequenceStart
-    // Missing function: SequenceUpdate
-    // Missing function: SequenceComplete
-    // Missing function: EventSequenceComplete
+    /// Starts an HMAC sequence.
+    ///
+    /// # Arguments
+    /// * `handle` - An [ObjectHandle] of a loaded keyed hash key.
+    /// * `auth` - The authorization value that is going to be used
+    ///            for the sequence object.
+    /// * `hashing_algorithm` - The [HashingAlgorithm] that is going to be
+    ///                         used in the HMAC computation.
+    ///
+    /// # Details
+    /// The returned [SequenceHandle] is used with [Context::sequence_update]
+    /// and [Context::sequence_complete] in order to compute an HMAC over data
+    /// that does not fit in a single [MaxBuffer].
+    ///
+    /// This command requires authorization for the key so an authorization
+    /// session needs to be set.
+    pub fn hmac_start(
+        &mut self,
+        handle: ObjectHandle,
+        auth: Option<Auth>,
+        hashing_algorithm: HashingAlgorithm,
+    ) -> Result<SequenceHandle> {
+        let mut sequence_handle = ObjectHandle::None.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_HMAC_Start(
+                    self.mut_context(),
+                    handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth.unwrap_or_default().into(),
+                    hashing_algorithm.into(),
+                    &mut sequence_handle,
+                )
+            },
+            |ret| {
+                error!("Error failed to start HMAC sequence: {:#010X}", ret);
+            },
+        )?;
+        let sequence_handle = SequenceHandle::from(sequence_handle);
+        self.handle_manager
+            .add_handle(sequence_handle.into(), HandleDropAction::Flush)?;
+        Ok(sequence_handle)
+    }
+
+    /// Starts a MAC sequence.
+    ///
+    /// # Arguments
+    /// * `handle` - An [ObjectHandle] of a loaded symmetric key or keyed hash key.
+    /// * `auth` - The authorization value that is going to be used
+    ///            for the sequence object.
+    /// * `mac_scheme` - The [MacSchemeAlgorithm] that is going to be
+    ///                  used in the MAC computation.
+    ///
+    /// # Details
+    /// The returned [SequenceHandle] is used with [Context::sequence_update]
+    /// and [Context::sequence_complete] in order to compute a MAC over data
+    /// that does not fit in a single [MaxBuffer], see [Context::mac].
+    ///
+    /// This command requires authorization for the key so an authorization
+    /// session needs to be set.
+    ///
+    /// The MAC commands were first added in the 4.0.0 version of the tpm2-tss and
+    /// are not part of the bundled bindings, so this method is only available when
+    /// the crate is built with the `generate-bindings` feature against that version
+    /// of the tpm2-tss or a later one.
+    #[cfg(has_esys_mac)]
+    pub fn mac_start(
+        &mut self,
+        handle: ObjectHandle,
+        auth: Option<Auth>,
+        mac_scheme: MacSchemeAlgorithm,
+    ) -> Result<SequenceHandle> {
+        let mut sequence_handle = ObjectHandle::None.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_MAC_Start(
+                    self.mut_context(),
+                    handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth.unwrap_or_default().into(),
+                    mac_scheme.into(),
+                    &mut sequence_handle,
+                )
+            },
+            |ret| {
+                error!("Error failed to start MAC sequence: {:#010X}", ret);
+            },
+        )?;
+        let sequence_handle = SequenceHandle::from(sequence_handle);
+        self.handle_manager
+            .add_handle(sequence_handle.into(), HandleDropAction::Flush)?;
+        Ok(sequence_handle)
+    }
+
+    /// Starts a hash or an event sequence.
+    ///
+    /// # Arguments
+    /// * `hashing_algorithm` - The [HashingAlgorithm] that is going to be used
+    ///                         in the sequence. If [HashingAlgorithm::Null] is used
+    ///                         then an event sequence is started.
+    /// * `auth` - The authorization value that is going to be used
+    ///            for the sequence object.
+    ///
+    /// # Details
+    /// A hash sequence is completed using [Context::sequence_complete] and an
+    /// event sequence is completed using [Context::event_sequence_complete].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, tcti_ldr::TctiNameConf,
+    /// #     structures::{MaxBuffer, Ticket},
+    /// #     interface_types::{
+    /// #         algorithm::HashingAlgorithm, reserved_handles::Hierarchy,
+    /// #         session_handles::AuthSession,
+    /// #     },
+    /// # };
+    /// # use std::convert::TryFrom;
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let sequence_handle = context
+    ///     .hash_sequence_start(HashingAlgorithm::Sha256, None)
+    ///     .expect("Call to hash_sequence_start failed");
+    ///
+    /// let (digest, ticket) = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     ctx.sequence_update(
+    ///         sequence_handle,
+    ///         MaxBuffer::try_from("There is ".as_bytes().to_vec())
+    ///             .expect("Failed to create buffer for input data."),
+    ///     )
+    ///     .expect("Call to sequence_update failed");
+    ///     ctx.sequence_complete(
+    ///         sequence_handle,
+    ///         MaxBuffer::try_from("no spoon".as_bytes().to_vec())
+    ///             .expect("Failed to create buffer for input data."),
+    ///         Hierarchy::Owner,
+    ///     )
+    ///     .expect("Call to sequence_complete failed")
+    /// });
+    ///
+    /// let expected_hashed_data: [u8; 32] = [
+    ///     0x6b, 0x38, 0x4d, 0x2b, 0xfb, 0x0e, 0x0d, 0xfb, 0x64, 0x89, 0xdb, 0xf4, 0xf8, 0xe9,
+    ///     0xe5, 0x2f, 0x71, 0xee, 0xb1, 0x0d, 0x06, 0x4c, 0x56, 0x59, 0x70, 0xcd, 0xd9, 0x44,
+    ///     0x43, 0x18, 0x5d, 0xc1,
+    /// ];
+    /// assert_eq!(&expected_hashed_data[..], digest.as_bytes());
+    /// assert_eq!(ticket.hierarchy(), Hierarchy::Owner);
+    /// ```
+    pub fn hash_sequence_start(
+        &mut self,
+        hashing_algorithm: HashingAlgorithm,
+        auth: Option<Auth>,
+    ) -> Result<SequenceHandle> {
+        let mut sequence_handle = ObjectHandle::None.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_HashSequenceStart(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth.unwrap_or_default().into(),
+                    hashing_algorithm.into(),
+                    &mut sequence_handle,
+                )
+            },
+            |ret| {
+                error!("Error failed to start hash sequence: {:#010X}", ret);
+            },
+        )?;
+        let sequence_handle = SequenceHandle::from(sequence_handle);
+        self.handle_manager
+            .add_handle(sequence_handle.into(), HandleDropAction::Flush)?;
+        Ok(sequence_handle)
+    }
+
+    /// Adds data to a hash, HMAC or event sequence.
+    ///
+    /// # Arguments
+    /// * `sequence_handle` - A [SequenceHandle] to the sequence.
+    /// * `buffer` - The data that is going to be added to the sequence.
+    ///
+    /// # Details
+    /// This command requires authorization for the sequence object so
+    /// an authorization session needs to be set.
+    pub fn sequence_update(
+        &mut self,
+        sequence_handle: SequenceHandle,
+        buffer: MaxBuffer,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SequenceUpdate(
+                    self.mut_context(),
+                    sequence_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &buffer.into(),
+                )
+            },
+            |ret| {
+                error!("Error failed to update sequence: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Adds the last part of the data to a hash or HMAC sequence
+    /// and returns the result.
+    ///
+    /// # Arguments
+    /// * `sequence_handle` - A [SequenceHandle] to the sequence.
+    /// * `buffer` - The last part of the data that is going to be added to the sequence.
+    /// * `hierarchy` - The [Hierarchy] that will be used for the ticket.
+    ///
+    /// # Details
+    /// The sequence object is flushed by the TPM when the sequence has been
+    /// completed, so the `sequence_handle` is no longer valid after this call.
+    ///
+    /// The returned [HashcheckTicket] indicates if the digest can be used in a
+    /// signing operation that uses a restricted signing key. For HMAC sequences,
+    /// or when the data started with `TPM_GENERATED_VALUE`, the ticket will be a
+    /// null ticket.
+    ///
+    /// This command requires authorization for the sequence object so
+    /// an authorization session needs to be set.
+    pub fn sequence_complete(
+        &mut self,
+        sequence_handle: SequenceHandle,
+        buffer: MaxBuffer,
+        hierarchy: Hierarchy,
+    ) -> Result<(Digest, HashcheckTicket)> {
+        let mut result_ptr = null_mut();
+        let mut validation_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SequenceComplete(
+                    self.mut_context(),
+                    sequence_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &buffer.into(),
+                    if cfg!(hierarchy_is_esys_tr) {
+                        ObjectHandle::from(hierarchy).into()
+                    } else {
+                        TpmHandle::from(hierarchy).into()
+                    },
+                    &mut result_ptr,
+                    &mut validation_ptr,
+                )
+            },
+            |ret| {
+                error!("Error failed to complete sequence: {:#010X}", ret);
+            },
+        )?;
+        self.handle_manager.set_as_flushed(sequence_handle.into())?;
+        Ok((
+            Digest::try_from(Context::ffi_data_to_owned(result_ptr))?,
+            HashcheckTicket::try_from(Context::ffi_data_to_owned(validation_ptr))?,
+        ))
+    }
+
+    /// Adds the last part of the data to an event sequence and
+    /// returns the result.
+    ///
+    /// # Arguments
+    /// * `pcr_handle` - An optional [PcrHandle] to a PCR that will be extended
+    ///                  with the resulting digests. If `None` is provided then no
+    ///                  PCR is extended.
+    /// * `sequence_handle` - A [SequenceHandle] to the event sequence.
+    /// * `buffer` - The last part of the data that is going to be added to the sequence.
+    ///
+    /// # Details
+    /// The returned [DigestValues] contains the digest of the event data for
+    /// each of the implemented hashing algorithms.
+    ///
+    /// The sequence object is flushed by the TPM when the sequence has been
+    /// completed, so the `sequence_handle` is no longer valid after this call.
+    ///
+    /// This command requires authorization for both the PCR and the sequence
+    /// object so two authorization sessions needs to be set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, tcti_ldr::TctiNameConf,
+    /// #     structures::MaxBuffer,
+    /// #     interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+    /// #     handles::PcrHandle,
+    /// # };
+    /// # use std::convert::TryFrom;
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// // Start an event sequence by using the Null hashing algorithm.
+    /// let sequence_handle = context
+    ///     .hash_sequence_start(HashingAlgorithm::Null, None)
+    ///     .expect("Call to hash_sequence_start failed");
+    ///
+    /// let digest_values = context.execute_with_sessions(
+    ///     (Some(AuthSession::Password), Some(AuthSession::Password), None),
+    ///     |ctx| {
+    ///         ctx.event_sequence_complete(
+    ///             Some(PcrHandle::Pcr16),
+    ///             sequence_handle,
+    ///             MaxBuffer::try_from("There is no spoon".as_bytes().to_vec())
+    ///                 .expect("Failed to create buffer for input data."),
+    ///         )
+    ///         .expect("Call to event_sequence_complete failed")
+    ///     },
+    /// );
+    /// assert!(digest_values.get(HashingAlgorithm::Sha256).is_some());
+    /// ```
+    pub fn event_sequence_complete(
+        &mut self,
+        pcr_handle: Option<PcrHandle>,
+        sequence_handle: SequenceHandle,
+        buffer: MaxBuffer,
+    ) -> Result<DigestValues> {
+        let mut results_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_EventSequenceComplete(
+                    self.mut_context(),
+                    pcr_handle
+                        .map_or(ObjectHandle::Null, ObjectHandle::from)
+                        .into(),
+                    sequence_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &buffer.into(),
+                    &mut results_ptr,
+                )
+            },
+            |ret| {
+                error!("Error failed to complete event sequence: {:#010X}", ret);
+            },
+        )?;
+        self.handle_manager.set_as_flushed(sequence_handle.into())?;
+        DigestValues::try_from(Context::ffi_data_to_owned(results_ptr))
+    }
 }
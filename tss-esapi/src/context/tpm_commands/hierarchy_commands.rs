@@ -3,12 +3,19 @@
 use crate::{
     context::handle_manager::HandleDropAction,
     handles::{AuthHandle, KeyHandle, ObjectHandle},
-    interface_types::{reserved_handles::Hierarchy, YesNo},
+    interface_types::{
+        algorithm::HashingAlgorithm,
+        reserved_handles::{Enables, Hierarchy, HierarchyPolicy, Platform},
+        YesNo,
+    },
     structures::{
         Auth, CreatePrimaryKeyResult, CreationData, CreationTicket, Data, Digest, PcrSelectionList,
         Public, SensitiveCreate, SensitiveData,
     },
-    tss2_esys::{Esys_Clear, Esys_ClearControl, Esys_CreatePrimary, Esys_HierarchyChangeAuth},
+    tss2_esys::{
+        Esys_ChangeEPS, Esys_ChangePPS, Esys_Clear, Esys_ClearControl, Esys_CreatePrimary,
+        Esys_HierarchyChangeAuth, Esys_HierarchyControl, Esys_SetPrimaryPolicy,
+    },
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -87,10 +94,183 @@ impl Context {
         })
     }
 
-    // Missing function: HierarchyControl
-    // Missing function: SetPrimaryPolicy
-    // Missing function: ChangePPS
-    // Missing function: ChangeEPS
+    /// Enables or disables the use of a hierarchy and its associated NV storage.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Hierarchy] that authorizes the change.
+    /// * `enable` - The [Enables] value that is going to be enabled or disabled.
+    /// * `state` - `true` if the hierarchy is to be enabled, `false` if it is to be disabled.
+    ///
+    /// # Details
+    /// A hierarchy can be disabled using its own authorization or the platform
+    /// authorization, but it can only be enabled using the platform authorization.
+    /// [Enables::PlatformNv] can only be changed using the platform authorization.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf,
+    /// #     interface_types::{
+    /// #         reserved_handles::{Enables, Hierarchy},
+    /// #         session_handles::AuthSession,
+    /// #     },
+    /// # };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     // Disable the endorsement hierarchy
+    ///     ctx.hierarchy_control(Hierarchy::Platform, Enables::Endorsement, false)
+    ///         .expect("Failed to disable the endorsement hierarchy");
+    ///     // Enable the endorsement hierarchy again
+    ///     ctx.hierarchy_control(Hierarchy::Platform, Enables::Endorsement, true)
+    ///         .expect("Failed to enable the endorsement hierarchy");
+    /// });
+    /// ```
+    pub fn hierarchy_control(
+        &mut self,
+        auth_handle: Hierarchy,
+        enable: Enables,
+        state: bool,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_HierarchyControl(
+                    self.mut_context(),
+                    ObjectHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    ObjectHandle::from(enable).into(),
+                    YesNo::from(state).into(),
+                )
+            },
+            |ret| {
+                error!("Error in controlling hierarchy: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Sets the authorization policy for a hierarchy.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [HierarchyPolicy] of the hierarchy whose policy is set.
+    /// * `auth_policy` - The policy digest.
+    /// * `hashing_algorithm` - The [HashingAlgorithm] used to compute the policy digest.
+    ///
+    /// # Details
+    /// The policy is removed from the hierarchy by providing an empty
+    /// `auth_policy` and [HashingAlgorithm::Null].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf,
+    /// #     interface_types::{
+    /// #         algorithm::HashingAlgorithm, reserved_handles::HierarchyPolicy,
+    /// #         session_handles::AuthSession,
+    /// #     },
+    /// #     structures::Digest,
+    /// # };
+    /// # use std::convert::TryFrom;
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     ctx.set_primary_policy(
+    ///         HierarchyPolicy::Owner,
+    ///         Digest::try_from(vec![0xaa; 32]).expect("Failed to create digest"),
+    ///         HashingAlgorithm::Sha256,
+    ///     )
+    ///     .expect("Failed to set the owner policy");
+    ///     // Remove the policy
+    ///     ctx.set_primary_policy(HierarchyPolicy::Owner, Digest::default(), HashingAlgorithm::Null)
+    ///         .expect("Failed to remove the owner policy");
+    /// });
+    /// ```
+    pub fn set_primary_policy(
+        &mut self,
+        auth_handle: HierarchyPolicy,
+        auth_policy: Digest,
+        hashing_algorithm: HashingAlgorithm,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SetPrimaryPolicy(
+                    self.mut_context(),
+                    ObjectHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth_policy.into(),
+                    hashing_algorithm.into(),
+                )
+            },
+            |ret| {
+                error!("Error in setting primary policy: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Replaces the current platform primary seed with a new value.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] authorization.
+    ///
+    /// # Details
+    /// All the transient and persistent objects in the platform hierarchy are
+    /// flushed by the TPM and the primary keys of the platform hierarchy will
+    /// change. The platform authorization value and policy are reset.
+    pub fn change_pps(&mut self, auth_handle: Platform) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ChangePPS(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error in changing the platform primary seed: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Replaces the current endorsement primary seed with a new value.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] authorization.
+    ///
+    /// # Details
+    /// All the transient and persistent objects in the endorsement hierarchy are
+    /// flushed by the TPM and the primary keys of the endorsement hierarchy (e.g. the
+    /// endorsement key) will change. The endorsement authorization value and policy
+    /// are reset.
+    pub fn change_eps(&mut self, auth_handle: Platform) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ChangeEPS(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error in changing the endorsement primary seed: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
 
     /// Clear all TPM context associated with a specific Owner
     pub fn clear(&mut self, auth_handle: AuthHandle) -> Result<()> {
@@ -1,9 +1,15 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    handles::PcrHandle,
-    structures::{DigestList, DigestValues, PcrSelectionList},
-    tss2_esys::{Esys_PCR_Extend, Esys_PCR_Read, Esys_PCR_Reset},
+    handles::{AuthHandle, PcrHandle, PcrTpmHandle},
+    interface_types::{algorithm::HashingAlgorithm, reserved_handles::Platform, YesNo},
+    structures::{
+        Auth, Digest, DigestList, DigestValues, Event, PcrAllocateResult, PcrSelectionList,
+    },
+    tss2_esys::{
+        Esys_PCR_Allocate, Esys_PCR_Event, Esys_PCR_Extend, Esys_PCR_Read, Esys_PCR_Reset,
+        Esys_PCR_SetAuthPolicy, Esys_PCR_SetAuthValue, TPM2_HANDLE,
+    },
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -105,7 +111,66 @@ impl Context {
         )
     }
 
-    // Missing function: PCR_Event
+    /// Extends a PCR with the digests of the event data.
+    ///
+    /// # Arguments
+    /// * `pcr_handle`- A [PcrHandle] to the PCR slot that is to be extended.
+    /// * `event_data` - The [Event] data that is hashed and extended into the PCR.
+    ///
+    /// # Details
+    /// The TPM computes the digest of the event data for each of the implemented
+    /// hashing algorithms and extends the PCR in each of the allocated banks with
+    /// the digest of the corresponding algorithm.
+    ///
+    /// # Returns
+    /// The [DigestValues] of the event data for each implemented hashing algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf,
+    /// #     handles::PcrHandle,
+    /// #     interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+    /// #     structures::Event,
+    /// # };
+    /// # use std::convert::TryFrom;
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let digests = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     ctx.pcr_event(
+    ///         PcrHandle::Pcr16,
+    ///         Event::try_from("There is no spoon".as_bytes().to_vec())
+    ///             .expect("Failed to create event data"),
+    ///     )
+    ///     .expect("Call to pcr_event failed")
+    /// });
+    /// assert!(digests.get(HashingAlgorithm::Sha256).is_some());
+    /// ```
+    pub fn pcr_event(&mut self, pcr_handle: PcrHandle, event_data: Event) -> Result<DigestValues> {
+        let mut digests_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_Event(
+                    self.mut_context(),
+                    pcr_handle.into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &event_data.into(),
+                    &mut digests_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when extending PCR with event: {:#010X}", ret);
+            },
+        )?;
+
+        DigestValues::try_from(Context::ffi_data_to_owned(digests_ptr))
+    }
 
     /// Reads the values of a PCR.
     ///
@@ -180,9 +245,128 @@ impl Context {
         ))
     }
 
-    // Missing function: PCR_Allocate
-    // Missing function: PCR_SetAuthPolicy
-    // Missing function: PCR_SetAuthValue
+    /// Sets the desired PCR allocation of the PCR banks.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] authorization.
+    /// * `pcr_allocation` - A [PcrSelectionList] with the PCRs that are to be
+    ///                      allocated in each bank.
+    ///
+    /// # Details
+    /// The new allocation takes effect after the next TPM reset. Banks that
+    /// are not present in `pcr_allocation` are left unchanged, so a bank is
+    /// removed by providing it with an empty selection.
+    ///
+    /// # Returns
+    /// A [PcrAllocateResult] indicating if the allocation succeeded, together
+    /// with the maximum number of PCRs in a bank and the number of octets that
+    /// are needed and available for the allocation.
+    pub fn pcr_allocate(
+        &mut self,
+        auth_handle: Platform,
+        pcr_allocation: PcrSelectionList,
+    ) -> Result<PcrAllocateResult> {
+        let mut allocation_success = YesNo::No.into();
+        let mut max_pcr = 0;
+        let mut size_needed = 0;
+        let mut size_available = 0;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_Allocate(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &pcr_allocation.into(),
+                    &mut allocation_success,
+                    &mut max_pcr,
+                    &mut size_needed,
+                    &mut size_available,
+                )
+            },
+            |ret| {
+                error!("Error when allocating PCR banks: {:#010X}", ret);
+            },
+        )?;
+
+        Ok(PcrAllocateResult {
+            allocation_success: YesNo::try_from(allocation_success)?.into(),
+            max_pcr,
+            size_needed,
+            size_available,
+        })
+    }
+
+    /// Associates a policy with a PCR or a group of PCRs.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] authorization.
+    /// * `auth_policy` - The policy digest.
+    /// * `hashing_algorithm` - The [HashingAlgorithm] used to compute the policy digest.
+    /// * `pcr_handle` - A [PcrHandle] to a PCR in the group whose policy is set.
+    ///
+    /// # Details
+    /// The policy applies to all the PCRs that are in the same policy group as
+    /// the PCR referenced by `pcr_handle`. The policy is removed by providing an
+    /// empty `auth_policy` and [HashingAlgorithm::Null].
+    pub fn pcr_set_auth_policy(
+        &mut self,
+        auth_handle: Platform,
+        auth_policy: Digest,
+        hashing_algorithm: HashingAlgorithm,
+        pcr_handle: PcrHandle,
+    ) -> Result<()> {
+        let pcr_num: TPM2_HANDLE = PcrTpmHandle::from(pcr_handle).into();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_SetAuthPolicy(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth_policy.into(),
+                    hashing_algorithm.into(),
+                    pcr_num,
+                )
+            },
+            |ret| {
+                error!("Error when setting PCR auth policy: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Changes the authorization value of a PCR or a group of PCRs.
+    ///
+    /// # Arguments
+    /// * `pcr_handle` - A [PcrHandle] to a PCR in the group whose authorization value is changed.
+    /// * `auth` - The new authorization value.
+    ///
+    /// # Details
+    /// The authorization value applies to all the PCRs that are in the same
+    /// authorization group as the PCR referenced by `pcr_handle`.
+    ///
+    /// The authorization value that is used by the context for the PCR is not
+    /// updated, so [Context::tr_set_auth] needs to be called for the PCR in order
+    /// to authorize it with the new value.
+    pub fn pcr_set_auth_value(&mut self, pcr_handle: PcrHandle, auth: Auth) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_SetAuthValue(
+                    self.mut_context(),
+                    pcr_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth.into(),
+                )
+            },
+            |ret| {
+                error!("Error when setting PCR auth value: {:#010X}", ret);
+            },
+        )
+    }
 
     /// Resets the value in a PCR.
     ///
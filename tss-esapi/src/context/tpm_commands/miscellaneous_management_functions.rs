@@ -1,8 +1,83 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::AuthHandle,
+    interface_types::reserved_handles::Platform,
+    structures::CommandCodeList,
+    tss2_esys::{Esys_PP_Commands, Esys_SetAlgorithmSet},
+    Context, Result, ReturnCode,
+};
+use log::error;
 
 impl Context {
-    // Missing function: PP_Commands
-    // Missing function: SetAlgorithmSet
+    /// Changes the list of commands that require physical presence.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Platform] authorization.
+    /// * `set_list` - The [CommandCodeList] of commands that are added to the
+    ///                list of commands that require physical presence.
+    /// * `clear_list` - The [CommandCodeList] of commands that are removed from
+    ///                  the list of commands that require physical presence.
+    ///
+    /// # Details
+    /// The platform authorization needs to be provided and physical presence
+    /// needs to be asserted, so an authorization session needs to be set.
+    ///
+    /// The current list of commands can be read using [Context::get_capability]
+    /// with [crate::constants::CapabilityType::PpCommands].
+    pub fn pp_commands(
+        &mut self,
+        auth: Platform,
+        set_list: CommandCodeList,
+        clear_list: CommandCodeList,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PP_Commands(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &set_list.into(),
+                    &clear_list.into(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when setting physical presence commands: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
+
+    /// Sets the algorithm set that is used by the TPM.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Platform] authorization.
+    /// * `algorithm_set` - The vendor dependent identifier of the algorithm set.
+    ///
+    /// # Details
+    /// The change takes effect after the next TPM reset.
+    ///
+    /// The platform authorization needs to be provided so an authorization
+    /// session needs to be set.
+    pub fn set_algorithm_set(&mut self, auth: Platform, algorithm_set: u32) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SetAlgorithmSet(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    algorithm_set,
+                )
+            },
+            |ret| {
+                error!("Error when setting algorithm set: {:#010X}", ret);
+            },
+        )
+    }
 }
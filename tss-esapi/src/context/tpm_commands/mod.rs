@@ -18,7 +18,7 @@ mod hierarchy_commands;
 mod integrity_collection_pcr;
 mod miscellaneous_management_functions;
 mod non_volatile_storage;
-mod object_commands;
+pub(crate) mod object_commands;
 mod random_number_generator;
 mod session_commands;
 mod signing_and_signature_verification;
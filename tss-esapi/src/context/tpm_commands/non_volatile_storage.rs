@@ -2,12 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     context::handle_manager::HandleDropAction,
-    handles::{AuthHandle, NvIndexHandle, ObjectHandle},
+    handles::{AuthHandle, KeyHandle, NvIndexHandle, ObjectHandle},
     interface_types::reserved_handles::{NvAuth, Provision},
-    structures::{Auth, MaxNvBuffer, Name, NvPublic},
+    structures::{
+        Attest, AttestBuffer, Auth, Data, MaxNvBuffer, Name, NvPublic, Signature, SignatureScheme,
+    },
     tss2_esys::{
-        Esys_NV_DefineSpace, Esys_NV_Increment, Esys_NV_Read, Esys_NV_ReadPublic,
-        Esys_NV_UndefineSpace, Esys_NV_UndefineSpaceSpecial, Esys_NV_Write,
+        Esys_NV_Certify, Esys_NV_ChangeAuth, Esys_NV_DefineSpace, Esys_NV_Extend,
+        Esys_NV_GlobalWriteLock, Esys_NV_Increment, Esys_NV_Read, Esys_NV_ReadLock,
+        Esys_NV_ReadPublic, Esys_NV_SetBits, Esys_NV_UndefineSpace, Esys_NV_UndefineSpaceSpecial,
+        Esys_NV_Write, Esys_NV_WriteLock,
     },
     Context, Result, ReturnCode,
 };
@@ -698,10 +702,219 @@ impl Context {
         )
     }
 
-    // Missing function: NV_Extend
-    // Missing function: NV_SetBits
-    // Missing function: NV_WriteLock
-    // Missing function: NV_GlobalWriteLock
+    /// Extends data to an NV index.
+    ///
+    /// # Details
+    /// This method is used to extend data into an NV index that
+    /// has been defined with the [Extend](crate::constants::NvIndexType::Extend)
+    /// type. The new value of the index is computed in the same way as a PCR extend,
+    /// using the name algorithm of the index.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       that is going to be extended.
+    /// * `data` - The data that is going to be extended to the NV index.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf, attributes::{SessionAttributes, NvIndexAttributes},
+    /// #     handles::NvIndexTpmHandle, interface_types::algorithm::HashingAlgorithm,
+    /// #     structures::{SymmetricDefinition, NvPublic}, constants::SessionType,
+    /// #     constants::nv_index_type::NvIndexType,
+    /// # };
+    /// use tss_esapi::{
+    ///       interface_types::reserved_handles::{Provision, NvAuth}, structures::MaxNvBuffer,
+    /// };
+    /// use std::convert::TryFrom;
+    ///
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// #
+    /// # let session = context
+    /// #     .start_auth_session(
+    /// #         None,
+    /// #         None,
+    /// #         None,
+    /// #         SessionType::Hmac,
+    /// #         SymmetricDefinition::AES_256_CFB,
+    /// #         tss_esapi::interface_types::algorithm::HashingAlgorithm::Sha256,
+    /// #     )
+    /// #     .expect("Failed to create session")
+    /// #     .expect("Received invalid handle");
+    /// # let (session_attributes, session_attributes_mask) = SessionAttributes::builder()
+    /// #     .with_decrypt(true)
+    /// #     .with_encrypt(true)
+    /// #     .build();
+    /// # context.tr_sess_set_attributes(session, session_attributes, session_attributes_mask)
+    /// #     .expect("Failed to set attributes on session");
+    /// # context.set_sessions((Some(session), None, None));
+    /// #
+    /// # let nv_index = NvIndexTpmHandle::new(0x01500028)
+    /// #     .expect("Failed to create NV index tpm handle");
+    /// #
+    /// # // Create NV index attributes
+    /// # let owner_nv_index_attributes = NvIndexAttributes::builder()
+    /// #     .with_owner_write(true)
+    /// #     .with_owner_read(true)
+    /// #     .with_nv_index_type(NvIndexType::Extend)
+    /// #     .build()
+    /// #     .expect("Failed to create owner nv index attributes");
+    /// #
+    /// # // Create owner nv public.
+    /// # let owner_nv_public = NvPublic::builder()
+    /// #     .with_nv_index(nv_index)
+    /// #     .with_index_name_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_index_attributes(owner_nv_index_attributes)
+    /// #     .with_data_area_size(32)
+    /// #     .build()
+    /// #     .expect("Failed to build NvPublic for owner");
+    /// #
+    /// let nv_index_handle = context
+    ///     .nv_define_space(Provision::Owner, None, owner_nv_public)
+    ///     .expect("Call to nv_define_space failed");
+    ///
+    /// let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+    ///     .expect("Failed to create MaxNvBuffer from vec");
+    /// let nv_extend_result = context.nv_extend(NvAuth::Owner, nv_index_handle, data);
+    ///
+    /// context
+    ///     .nv_undefine_space(Provision::Owner, nv_index_handle)
+    ///     .expect("Call to nv_undefine_space failed");
+    ///
+    /// // Process result
+    /// nv_extend_result.expect("Call to nv_extend failed");
+    /// ```
+    pub fn nv_extend(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        data: MaxNvBuffer,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_Extend(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &data.into(),
+                )
+            },
+            |ret| error!("Error when extending NV: {:#010X}", ret),
+        )
+    }
+
+    /// Sets bits in a bit field NV index.
+    ///
+    /// # Details
+    /// This method is used to OR the provided `bits` with
+    /// the current value of an NV index that has been defined
+    /// with the [Bits](crate::constants::NvIndexType::Bits) type.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       where the bits are going to be set.
+    /// * `bits` - The bits that are going to be set in the NV index.
+    pub fn nv_set_bits(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        bits: u64,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_SetBits(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    bits,
+                )
+            },
+            |ret| error!("Error when setting bits in NV: {:#010X}", ret),
+        )
+    }
+
+    /// Prevents further writes to an NV index.
+    ///
+    /// # Details
+    /// This method is used to set the write lock of an NV index
+    /// that has been defined with either the `TPMA_NV_WRITEDEFINE`
+    /// or the `TPMA_NV_WRITE_STCLEAR` attribute set.
+    ///
+    /// If the index has the `TPMA_NV_WRITEDEFINE` attribute set then
+    /// the lock will remain until the index is undefined, otherwise
+    /// it will remain until the next TPM Reset or TPM Restart.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       that is going to be write locked.
+    pub fn nv_write_lock(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_WriteLock(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| error!("Error when write locking NV: {:#010X}", ret),
+        )
+    }
+
+    /// Prevents further writes to all NV indices that have the global lock attribute set.
+    ///
+    /// # Details
+    /// This method is used to set the write lock of all the NV indices
+    /// that have been defined with the `TPMA_NV_GLOBALLOCK` attribute set.
+    /// The lock will remain until the next TPM Reset or TPM Restart.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization.
+    pub fn nv_global_write_lock(&mut self, auth: Provision) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_GlobalWriteLock(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| error!("Error when global write locking NV: {:#010X}", ret),
+        )
+    }
 
     /// Reads data from the nv index.
     ///
@@ -828,7 +1041,137 @@ impl Context {
         MaxNvBuffer::try_from(Context::ffi_data_to_owned(data_ptr))
     }
 
-    // Missing function: NV_ReadLock
-    // Missing function: NV_ChangeAuth
-    // Missing function: NV_Certify
+    /// Prevents further reads of an NV index.
+    ///
+    /// # Details
+    /// This method is used to set the read lock of an NV index
+    /// that has been defined with the `TPMA_NV_READ_STCLEAR` attribute set.
+    /// The lock will remain until the next TPM Reset or TPM Restart.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       that is going to be read locked.
+    pub fn nv_read_lock(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_ReadLock(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| error!("Error when read locking NV: {:#010X}", ret),
+        )
+    }
+
+    /// Changes the authorization value of an NV index.
+    ///
+    /// # Details
+    /// This command can only be authorized using a policy session, that
+    /// satisfies the policy of the NV index. The policy is required to
+    /// include a [Context::policy_command_code] assertion with the
+    /// `NvChangeAuth` command code.
+    ///
+    /// The new authorization value is used for the `nv_index_handle` in
+    /// subsequent commands.
+    ///
+    /// # Arguments
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       for which the authorization value is changed.
+    /// * `new_auth` - The new authorization value.
+    pub fn nv_change_auth(&mut self, nv_index_handle: NvIndexHandle, new_auth: Auth) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_ChangeAuth(
+                    self.mut_context(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &new_auth.into(),
+                )
+            },
+            |ret| error!("Error when changing the auth of NV: {:#010X}", ret),
+        )
+    }
+
+    /// Certifies the contents of an NV index.
+    ///
+    /// # Details
+    /// The TPM signs an attestation structure containing either the
+    /// contents of the NV index, or a digest of them, using the key
+    /// associated with `signing_key_handle`.
+    ///
+    /// If both `size` and `offset` are zero the TPM will sign the digest
+    /// of the contents of the index and the attested info will be of
+    /// the `NvDigest` type, if this is supported. Otherwise the requested
+    /// range of the contents is included in the attested info which will
+    /// be of the `Nv` type.
+    ///
+    /// Please beware that this method requires two authorization sessions,
+    /// the first one for `signing_key_handle` and the second one for `auth_handle`.
+    ///
+    /// # Arguments
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation buffer.
+    /// * `auth_handle` - Handle indicating the source of authorization value for the NV index.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       that is going to be certified.
+    /// * `qualifying_data` - Qualifying data.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    /// * `size` - The number of octets to certify.
+    /// * `offset` - Octet offset into the NV area.
+    #[allow(clippy::too_many_arguments)]
+    pub fn nv_certify(
+        &mut self,
+        signing_key_handle: KeyHandle,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+        size: u16,
+        offset: u16,
+    ) -> Result<(Attest, Signature)> {
+        let mut certify_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_Certify(
+                    self.mut_context(),
+                    signing_key_handle.into(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    size,
+                    offset,
+                    &mut certify_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when certifying NV: {:#010X}", ret);
+            },
+        )?;
+
+        let certify_info = Context::ffi_data_to_owned(certify_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(certify_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
 }
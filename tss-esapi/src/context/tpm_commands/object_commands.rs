@@ -1,7 +1,7 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-mod create_command_input;
-mod create_command_output;
+pub(crate) mod create_command_input;
+pub(crate) mod create_command_output;
 
 use crate::{
     context::handle_manager::HandleDropAction,
@@ -9,11 +9,11 @@ use crate::{
     interface_types::reserved_handles::Hierarchy,
     structures::{
         Auth, CreateKeyResult, Data, Digest, EncryptedSecret, IdObject, Name, PcrSelectionList,
-        Private, Public, Sensitive, SensitiveData,
+        Private, Public, Sensitive, SensitiveCreate, SensitiveData, TemplateBuffer,
     },
     tss2_esys::{
-        Esys_ActivateCredential, Esys_Create, Esys_Load, Esys_LoadExternal, Esys_MakeCredential,
-        Esys_ObjectChangeAuth, Esys_ReadPublic, Esys_Unseal,
+        Esys_ActivateCredential, Esys_Create, Esys_CreateLoaded, Esys_Load, Esys_LoadExternal,
+        Esys_MakeCredential, Esys_ObjectChangeAuth, Esys_ReadPublic, Esys_Unseal,
     },
     Context, Result, ReturnCode,
 };
@@ -338,5 +338,65 @@ impl Context {
         Private::try_from(Context::ffi_data_to_owned(out_private_ptr))
     }
 
-    // Missing function: CreateLoaded
+    /// Create an object and load it into the TPM.
+    ///
+    /// # Arguments
+    /// * `parent_handle` - The [ObjectHandle] of the parent for the new object. This can be
+    ///                     a storage parent, a derivation parent or a hierarchy, in which case
+    ///                     a primary object is created.
+    /// * `template` - The [TemplateBuffer] containing the public template of the object.
+    /// * `auth_value` - The value used to be used for authorize usage of the object.
+    /// * `sensitive_data` - The data that is to be sealed or a key.
+    ///
+    /// # Details
+    /// This command combines [Context::create] and [Context::load] in a single
+    /// command. When the parent is a derivation parent the template needs to contain
+    /// the label and context used in the derivation, see [TemplateBuffer::with_derive].
+    ///
+    /// # Returns
+    /// The [KeyHandle] of the loaded object together with its [Private] and [Public] parts.
+    /// The private part is only usable, e.g. with [Context::load], if the object was created
+    /// under a storage parent.
+    pub fn create_loaded(
+        &mut self,
+        parent_handle: ObjectHandle,
+        template: TemplateBuffer,
+        auth_value: Option<Auth>,
+        sensitive_data: Option<SensitiveData>,
+    ) -> Result<(KeyHandle, Private, Public)> {
+        let sensitive_create = SensitiveCreate::new(
+            auth_value.unwrap_or_default(),
+            sensitive_data.unwrap_or_default(),
+        );
+        let mut object_handle = ObjectHandle::None.into();
+        let mut out_private_ptr = null_mut();
+        let mut out_public_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_CreateLoaded(
+                    self.mut_context(),
+                    parent_handle.into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &sensitive_create.try_into()?,
+                    &template.into(),
+                    &mut object_handle,
+                    &mut out_private_ptr,
+                    &mut out_public_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in creating loaded object: {:#010X}", ret);
+            },
+        )?;
+        let key_handle = KeyHandle::from(object_handle);
+        self.handle_manager
+            .add_handle(key_handle.into(), HandleDropAction::Flush)?;
+        Ok((
+            key_handle,
+            Private::try_from(Context::ffi_data_to_owned(out_private_ptr))?,
+            Public::try_from(Context::ffi_data_to_owned(out_public_ptr))?,
+        ))
+    }
 }
@@ -14,6 +14,11 @@ use log::error;
 use std::convert::TryFrom;
 use std::ptr::null_mut;
 
+// The MAC command was first added to
+// the ESAPI in the 4.0.0 version of the tpm2-tss.
+#[cfg(has_esys_mac)]
+use crate::{interface_types::algorithm::MacSchemeAlgorithm, tss2_esys::Esys_MAC};
+
 impl Context {
     // Missing function: EncryptDecrypt, deprecated use EncryptDecrypt2 instead.
 
@@ -372,5 +377,54 @@ impl Context {
         Digest::try_from(Context::ffi_data_to_owned(out_hmac_ptr))
     }
 
-    // Missing function: MAC
+    /// Computes a MAC over the data using the key associated with the `handle`.
+    ///
+    /// # Arguments
+    /// * `handle` - An [ObjectHandle] of a loaded symmetric key or keyed hash key.
+    /// * `buffer` - The data over which the MAC is computed.
+    /// * `mac_scheme` - The [MacSchemeAlgorithm] that is used.
+    ///
+    /// # Details
+    /// For keyed hash keys the scheme selects the hashing algorithm of the HMAC,
+    /// for symmetric block cipher keys [MacSchemeAlgorithm::Cmac] is used. If the
+    /// key has a scheme set then `mac_scheme` needs to either match it or be
+    /// [MacSchemeAlgorithm::Null].
+    ///
+    /// This command requires authorization for the key so an authorization
+    /// session needs to be set.
+    ///
+    /// The MAC commands were first added in the 4.0.0 version of the tpm2-tss and
+    /// are not part of the bundled bindings, so this method is only available when
+    /// the crate is built with the `generate-bindings` feature against that version
+    /// of the tpm2-tss or a later one.
+    ///
+    /// # Returns
+    /// The computed MAC.
+    #[cfg(has_esys_mac)]
+    pub fn mac(
+        &mut self,
+        handle: ObjectHandle,
+        buffer: MaxBuffer,
+        mac_scheme: MacSchemeAlgorithm,
+    ) -> Result<Digest> {
+        let mut out_mac_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_MAC(
+                    self.mut_context(),
+                    handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &buffer.into(),
+                    mac_scheme.into(),
+                    &mut out_mac_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in mac: {:#010X}", ret);
+            },
+        )?;
+        Digest::try_from(Context::ffi_data_to_owned(out_mac_ptr))
+    }
 }
@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     interface_types::YesNo,
-    structures::MaxBuffer,
-    tss2_esys::{Esys_GetTestResult, Esys_SelfTest},
+    structures::{AlgorithmList, MaxBuffer},
+    tss2_esys::{Esys_GetTestResult, Esys_IncrementalSelfTest, Esys_SelfTest},
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -29,7 +29,41 @@ impl Context {
         )
     }
 
-    // Missing function: incremental_self_test
+    /// Execute the TPM self test of the specified algorithms
+    ///
+    /// # Arguments
+    /// * `to_test` - The [AlgorithmList] containing the algorithms that should be tested.
+    ///
+    /// # Details
+    /// The TPM tests the algorithms that have not yet been tested. The tests may be
+    /// performed in the background, in which case other commands may return a
+    /// `Testing` [TpmFormatZeroWarning](crate::constants::return_code::TpmFormatZeroWarning)
+    /// until they are complete.
+    ///
+    /// See [crate::abstraction::self_test::test_algorithms] for a helper that
+    /// waits until the tests of the algorithms have been completed.
+    ///
+    /// # Returns
+    /// An [AlgorithmList] containing the algorithms that remain to be tested.
+    pub fn incremental_self_test(&mut self, to_test: AlgorithmList) -> Result<AlgorithmList> {
+        let mut to_do_list_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_IncrementalSelfTest(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &to_test.into(),
+                    &mut to_do_list_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in incremental self-test: {:#010X}", ret);
+            },
+        )?;
+        AlgorithmList::try_from(Context::ffi_data_to_owned(to_do_list_ptr))
+    }
 
     /// Get the TPM self test result
     ///
@@ -1,7 +1,41 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{structures::Data, tss2_esys::Esys_Vendor_TCG_Test, Context, Result, ReturnCode};
+use log::error;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: Vendor_TCG_Test
+    /// Executes the TCG test command.
+    ///
+    /// # Arguments
+    /// * `input_data` - Test data.
+    ///
+    /// # Details
+    /// The command is provided by the TPM vendors for testing purposes
+    /// and the contents of the returned data are vendor specific.
+    pub fn vendor_tcg_test(&mut self, input_data: Data) -> Result<Data> {
+        let mut output_data_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Vendor_TCG_Test(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &input_data.into(),
+                    &mut output_data_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in vendor TCG test: {:#010X}", ret);
+            },
+        )?;
+        Data::try_from(Context::ffi_data_to_owned(output_data_ptr))
+    }
+
+    // Missing function: generic vendor specific commands. The ESAPI only computes
+    // the session HMACs and the parameter encryption for the commands it implements
+    // and does not expose the session keys, so commands with vendor specific command
+    // codes cannot be authorized with sessions the way the ESAPI authorizes commands.
 }
@@ -346,3 +346,18 @@ pub mod session {
     impl_handle_conversion!(SessionHandle, ObjectHandle);
     impl_handle_conversion!(SessionHandle, AuthHandle);
 }
+
+/// Sequence handle module
+pub mod sequence {
+    use super::auth::AuthHandle;
+    use super::object::ObjectHandle;
+    impl_basic_handle!(
+        /// Sequence Handle
+        ///
+        /// Represents an esys resource handle
+        /// for a hash, HMAC or event sequence object.
+        SequenceHandle
+    );
+    impl_handle_conversion!(SequenceHandle, ObjectHandle);
+    impl_handle_conversion!(SequenceHandle, AuthHandle);
+}
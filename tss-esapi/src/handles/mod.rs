@@ -13,6 +13,7 @@ pub use handle::key::KeyHandle;
 pub use handle::nv_index::NvIndexHandle;
 pub use handle::object::ObjectHandle;
 pub use handle::pcr::PcrHandle;
+pub use handle::sequence::SequenceHandle;
 pub use handle::session::SessionHandle;
 pub(crate) mod handle_conversion {
     pub(crate) use super::handle::conversions::*;
@@ -186,7 +186,10 @@ macro_rules! add_constant_handle {
 pub mod pcr {
     //! Module for the PCR TPM handle.
     use super::*;
-    use crate::constants::tss::{TPM2_HT_PCR, TPM2_PCR_FIRST, TPM2_PCR_LAST};
+    use crate::{
+        constants::tss::{TPM2_HT_PCR, TPM2_PCR_FIRST, TPM2_PCR_LAST},
+        handles::PcrHandle,
+    };
     // Creates the specific handle types
     create_tpm_handle_type!(
         PcrTpmHandle,
@@ -195,6 +198,48 @@ pub mod pcr {
         TPM2_PCR_FIRST,
         TPM2_PCR_LAST
     );
+
+    impl From<PcrHandle> for PcrTpmHandle {
+        fn from(pcr_handle: PcrHandle) -> PcrTpmHandle {
+            let pcr_number: u32 = match pcr_handle {
+                PcrHandle::Pcr0 => 0,
+                PcrHandle::Pcr1 => 1,
+                PcrHandle::Pcr2 => 2,
+                PcrHandle::Pcr3 => 3,
+                PcrHandle::Pcr4 => 4,
+                PcrHandle::Pcr5 => 5,
+                PcrHandle::Pcr6 => 6,
+                PcrHandle::Pcr7 => 7,
+                PcrHandle::Pcr8 => 8,
+                PcrHandle::Pcr9 => 9,
+                PcrHandle::Pcr10 => 10,
+                PcrHandle::Pcr11 => 11,
+                PcrHandle::Pcr12 => 12,
+                PcrHandle::Pcr13 => 13,
+                PcrHandle::Pcr14 => 14,
+                PcrHandle::Pcr15 => 15,
+                PcrHandle::Pcr16 => 16,
+                PcrHandle::Pcr17 => 17,
+                PcrHandle::Pcr18 => 18,
+                PcrHandle::Pcr19 => 19,
+                PcrHandle::Pcr20 => 20,
+                PcrHandle::Pcr21 => 21,
+                PcrHandle::Pcr22 => 22,
+                PcrHandle::Pcr23 => 23,
+                PcrHandle::Pcr24 => 24,
+                PcrHandle::Pcr25 => 25,
+                PcrHandle::Pcr26 => 26,
+                PcrHandle::Pcr27 => 27,
+                PcrHandle::Pcr28 => 28,
+                PcrHandle::Pcr29 => 29,
+                PcrHandle::Pcr30 => 30,
+                PcrHandle::Pcr31 => 31,
+            };
+            PcrTpmHandle {
+                value: TPM2_PCR_FIRST + pcr_number,
+            }
+        }
+    }
 }
 
 pub mod nv_index {
@@ -1,5 +1,10 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_act)]
+use crate::{
+    constants::tss::{TPM2_RH_ACT_0, TPM2_RH_ACT_F},
+    tss2_esys::{ESYS_TR_RH_ACT_FIRST, ESYS_TR_RH_ACT_LAST},
+};
 use crate::{
     handles::{
         AttachedComponentTpmHandle, AuthHandle, NvIndexHandle, NvIndexTpmHandle, ObjectHandle,
@@ -7,6 +12,8 @@ use crate::{
     },
     Error, Result, WrapperErrorKind,
 };
+#[cfg(has_esys_act)]
+use log::error;
 use std::convert::TryFrom;
 //////////////////////////////////////////////////////////////////////////////////
 /// Hierarchy
@@ -207,8 +214,80 @@ impl TryFrom<TpmHandle> for HierarchyAuth {
     }
 }
 //////////////////////////////////////////////////////////////////////////////////
-/// TODO: HierarchyPolicy
+/// HierarchyPolicy
 //////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HierarchyPolicy {
+    Owner,
+    Platform,
+    Endorsement,
+    Lockout,
+    #[cfg(has_esys_act)]
+    Act(Act),
+}
+
+impl From<HierarchyPolicy> for ObjectHandle {
+    fn from(hierarchy_policy: HierarchyPolicy) -> ObjectHandle {
+        match hierarchy_policy {
+            HierarchyPolicy::Owner => ObjectHandle::Owner,
+            HierarchyPolicy::Platform => ObjectHandle::Platform,
+            HierarchyPolicy::Endorsement => ObjectHandle::Endorsement,
+            HierarchyPolicy::Lockout => ObjectHandle::Lockout,
+            #[cfg(has_esys_act)]
+            HierarchyPolicy::Act(act) => act.into(),
+        }
+    }
+}
+
+impl From<HierarchyPolicy> for TpmHandle {
+    fn from(hierarchy_policy: HierarchyPolicy) -> TpmHandle {
+        match hierarchy_policy {
+            HierarchyPolicy::Owner => TpmHandle::Permanent(PermanentTpmHandle::Owner),
+            HierarchyPolicy::Platform => TpmHandle::Permanent(PermanentTpmHandle::Platform),
+            HierarchyPolicy::Endorsement => TpmHandle::Permanent(PermanentTpmHandle::Endorsement),
+            HierarchyPolicy::Lockout => TpmHandle::Permanent(PermanentTpmHandle::Lockout),
+            #[cfg(has_esys_act)]
+            HierarchyPolicy::Act(act) => act.into(),
+        }
+    }
+}
+
+impl TryFrom<ObjectHandle> for HierarchyPolicy {
+    type Error = Error;
+
+    fn try_from(object_handle: ObjectHandle) -> Result<HierarchyPolicy> {
+        match object_handle {
+            ObjectHandle::Owner => Ok(HierarchyPolicy::Owner),
+            ObjectHandle::Platform => Ok(HierarchyPolicy::Platform),
+            ObjectHandle::Endorsement => Ok(HierarchyPolicy::Endorsement),
+            ObjectHandle::Lockout => Ok(HierarchyPolicy::Lockout),
+            #[cfg(has_esys_act)]
+            _ => Act::try_from(object_handle).map(HierarchyPolicy::Act),
+            #[cfg(not(has_esys_act))]
+            _ => Err(Error::local_error(WrapperErrorKind::InvalidParam)),
+        }
+    }
+}
+
+impl TryFrom<TpmHandle> for HierarchyPolicy {
+    type Error = Error;
+
+    fn try_from(tpm_handle: TpmHandle) -> Result<HierarchyPolicy> {
+        match tpm_handle {
+            TpmHandle::Permanent(permanent_handle) => match permanent_handle {
+                PermanentTpmHandle::Owner => Ok(HierarchyPolicy::Owner),
+                PermanentTpmHandle::Platform => Ok(HierarchyPolicy::Platform),
+                PermanentTpmHandle::Endorsement => Ok(HierarchyPolicy::Endorsement),
+                PermanentTpmHandle::Lockout => Ok(HierarchyPolicy::Lockout),
+                #[cfg(has_esys_act)]
+                _ => Act::try_from(tpm_handle).map(HierarchyPolicy::Act),
+                #[cfg(not(has_esys_act))]
+                _ => Err(Error::local_error(WrapperErrorKind::InvalidParam)),
+            },
+            _ => Err(Error::local_error(WrapperErrorKind::InvalidParam)),
+        }
+    }
+}
 
 //////////////////////////////////////////////////////////////////////////////////
 /// Platform
@@ -451,7 +530,140 @@ impl From<AttachedComponent> for AttachedComponentTpmHandle {
 }
 
 //////////////////////////////////////////////////////////////////////////////////
-// Act (authenticated timers)
-//
-// TODO: Figure out how to implement this. This is some kind of counter.
+/// Act
+///
+/// Enum describing the authenticated countdown timers (ACT) in a TPM 2.0.
 //////////////////////////////////////////////////////////////////////////////////
+#[cfg(has_esys_act)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Act {
+    Act0,
+    Act1,
+    Act2,
+    Act3,
+    Act4,
+    Act5,
+    Act6,
+    Act7,
+    Act8,
+    Act9,
+    ActA,
+    ActB,
+    ActC,
+    ActD,
+    ActE,
+    ActF,
+}
+
+#[cfg(has_esys_act)]
+impl Act {
+    const ALL: [Act; 16] = [
+        Act::Act0,
+        Act::Act1,
+        Act::Act2,
+        Act::Act3,
+        Act::Act4,
+        Act::Act5,
+        Act::Act6,
+        Act::Act7,
+        Act::Act8,
+        Act::Act9,
+        Act::ActA,
+        Act::ActB,
+        Act::ActC,
+        Act::ActD,
+        Act::ActE,
+        Act::ActF,
+    ];
+
+    /// Returns the number of the ACT.
+    pub const fn number(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the ACT with the specified number.
+    ///
+    /// # Errors
+    /// If the number is larger than 15 an `InvalidParam` error is returned.
+    pub fn from_number(number: u8) -> Result<Act> {
+        Act::ALL.get(usize::from(number)).copied().ok_or_else(|| {
+            error!("Invalid ACT number {}", number);
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })
+    }
+
+    /// Returns the ACT with the specified number, if the
+    /// value is within the specified range.
+    fn from_range(value: u32, first: u32, last: u32) -> Result<Act> {
+        if (first..=last).contains(&value) {
+            // The range contains 16 values so the offset fits in an u8.
+            Act::from_number((value - first) as u8)
+        } else {
+            Err(Error::local_error(WrapperErrorKind::InvalidParam))
+        }
+    }
+}
+
+#[cfg(has_esys_act)]
+impl From<Act> for ObjectHandle {
+    fn from(act: Act) -> ObjectHandle {
+        ObjectHandle::from(ESYS_TR_RH_ACT_FIRST + u32::from(act.number()))
+    }
+}
+
+#[cfg(has_esys_act)]
+impl From<Act> for AuthHandle {
+    fn from(act: Act) -> AuthHandle {
+        AuthHandle::from(ESYS_TR_RH_ACT_FIRST + u32::from(act.number()))
+    }
+}
+
+#[cfg(has_esys_act)]
+impl From<Act> for TpmHandle {
+    fn from(act: Act) -> TpmHandle {
+        // The ACT handles are in the range of the permanent handles so this cannot fail.
+        TpmHandle::Permanent(
+            PermanentTpmHandle::new(TPM2_RH_ACT_0 + u32::from(act.number())).unwrap(),
+        )
+    }
+}
+
+#[cfg(has_esys_act)]
+impl TryFrom<ObjectHandle> for Act {
+    type Error = Error;
+
+    fn try_from(object_handle: ObjectHandle) -> Result<Act> {
+        Act::from_range(
+            object_handle.into(),
+            ESYS_TR_RH_ACT_FIRST,
+            ESYS_TR_RH_ACT_LAST,
+        )
+    }
+}
+
+#[cfg(has_esys_act)]
+impl TryFrom<AuthHandle> for Act {
+    type Error = Error;
+
+    fn try_from(auth_handle: AuthHandle) -> Result<Act> {
+        Act::from_range(
+            auth_handle.into(),
+            ESYS_TR_RH_ACT_FIRST,
+            ESYS_TR_RH_ACT_LAST,
+        )
+    }
+}
+
+#[cfg(has_esys_act)]
+impl TryFrom<TpmHandle> for Act {
+    type Error = Error;
+
+    fn try_from(tpm_handle: TpmHandle) -> Result<Act> {
+        match tpm_handle {
+            TpmHandle::Permanent(permanent_handle) => {
+                Act::from_range(permanent_handle.into(), TPM2_RH_ACT_0, TPM2_RH_ACT_F)
+            }
+            _ => Err(Error::local_error(WrapperErrorKind::InvalidParam)),
+        }
+    }
+}
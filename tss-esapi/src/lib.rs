@@ -106,11 +106,14 @@ pub mod constants;
 pub mod handles;
 pub mod interface_types;
 pub mod structures;
+pub mod tcti;
 pub mod tcti_ldr;
 pub mod traits;
 pub mod utils;
 
 pub use abstraction::transient::TransientKeyContext;
+#[cfg(feature = "async")]
+pub use context::AsyncContext;
 pub use context::Context;
 pub use error::{Error, Result, ReturnCode, WrapperErrorKind};
 pub use tcti_ldr::TctiNameConf;
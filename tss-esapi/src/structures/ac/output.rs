@@ -0,0 +1,49 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{constants::AcCapability, tss2_esys::TPMS_AC_OUTPUT, Error, Result};
+use std::convert::TryFrom;
+
+/// Structure holding a tagged value returned by an attached component.
+///
+/// # Details
+/// This corresponds to TPMS_AC_OUTPUT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcOutput {
+    tag: AcCapability,
+    data: u32,
+}
+
+impl AcOutput {
+    /// Creates a new AcOutput
+    pub const fn new(tag: AcCapability, data: u32) -> Self {
+        AcOutput { tag, data }
+    }
+
+    /// Returns the tag
+    pub const fn tag(&self) -> AcCapability {
+        self.tag
+    }
+
+    /// Returns the data
+    pub const fn data(&self) -> u32 {
+        self.data
+    }
+}
+
+impl TryFrom<TPMS_AC_OUTPUT> for AcOutput {
+    type Error = Error;
+
+    fn try_from(tpms_ac_output: TPMS_AC_OUTPUT) -> Result<Self> {
+        let data = tpms_ac_output.data;
+        AcCapability::try_from(tpms_ac_output.tag).map(|tag| AcOutput { tag, data })
+    }
+}
+
+impl From<AcOutput> for TPMS_AC_OUTPUT {
+    fn from(ac_output: AcOutput) -> Self {
+        TPMS_AC_OUTPUT {
+            tag: ac_output.tag.into(),
+            data: ac_output.data,
+        }
+    }
+}
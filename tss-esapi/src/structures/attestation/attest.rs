@@ -1,6 +1,8 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(has_tpmu_attest_nv_digest)]
+use crate::tss2_esys::TPMS_NV_DIGEST_CERTIFY_INFO;
 use crate::{
     constants::tss::TPM2_GENERATED_VALUE,
     interface_types::structure_tags::AttestationType,
@@ -9,6 +11,7 @@ use crate::{
     tss2_esys::TPMS_ATTEST,
     Error, Result, WrapperErrorKind,
 };
+#[cfg(not(has_tpmu_attest_nv_digest))]
 use log::error;
 use std::convert::{TryFrom, TryInto};
 
@@ -109,6 +112,17 @@ impl TryFrom<TPMS_ATTEST> for Attest {
                 AttestationType::Nv => AttestInfo::Nv {
                     info: unsafe { tpms_attest.attested.nv }.try_into()?,
                 },
+                #[cfg(has_tpmu_attest_nv_digest)]
+                AttestationType::NvDigest => AttestInfo::NvDigest {
+                    // The bundled bindings lack the nvDigest member of the union,
+                    // so it is accessed through a pointer to the structure.
+                    info: unsafe {
+                        *(std::ptr::addr_of!(tpms_attest.attested)
+                            as *const TPMS_NV_DIGEST_CERTIFY_INFO)
+                    }
+                    .try_into()?,
+                },
+                #[cfg(not(has_tpmu_attest_nv_digest))]
                 AttestationType::NvDigest => {
                     error!("NvDigest attestation type is currently not supported");
                     return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
@@ -9,6 +9,9 @@ use crate::{
     tss2_esys::TPMU_ATTEST,
 };
 
+#[cfg(has_tpmu_attest_nv_digest)]
+use crate::{structures::NvDigestCertifyInfo, tss2_esys::TPMS_NV_DIGEST_CERTIFY_INFO};
+
 /// Enum that holds the different types of
 /// attest info.
 ///
@@ -18,15 +21,34 @@ use crate::{
 /// included.
 #[derive(Debug, Clone)]
 pub enum AttestInfo {
-    Certify { info: CertifyInfo },
-    Quote { info: QuoteInfo },
-    SessionAudit { info: SessionAuditInfo },
-    CommandAudit { info: CommandAuditInfo },
-    Time { info: TimeAttestInfo },
-    Creation { info: CreationInfo },
-    Nv { info: NvCertifyInfo },
-    // NvDigest, the TPMS_NV_DIGEST_CERTIFY_INFO,
-    // was first added in the 3.1.0 version of the tpm2-tss
+    Certify {
+        info: CertifyInfo,
+    },
+    Quote {
+        info: QuoteInfo,
+    },
+    SessionAudit {
+        info: SessionAuditInfo,
+    },
+    CommandAudit {
+        info: CommandAuditInfo,
+    },
+    Time {
+        info: TimeAttestInfo,
+    },
+    Creation {
+        info: CreationInfo,
+    },
+    Nv {
+        info: NvCertifyInfo,
+    },
+    // The TPMS_NV_DIGEST_CERTIFY_INFO structure was first added in the
+    // 3.1.0 version of the tpm2-tss, but the nvDigest member of the
+    // TPMU_ATTEST union, which is needed to marshal it, only in 4.0.0
+    #[cfg(has_tpmu_attest_nv_digest)]
+    NvDigest {
+        info: NvDigestCertifyInfo,
+    },
 }
 
 impl From<AttestInfo> for TPMU_ATTEST {
@@ -47,6 +69,17 @@ impl From<AttestInfo> for TPMU_ATTEST {
                 creation: info.into(),
             },
             AttestInfo::Nv { info } => TPMU_ATTEST { nv: info.into() },
+            #[cfg(has_tpmu_attest_nv_digest)]
+            AttestInfo::NvDigest { info } => {
+                // The bundled bindings lack the nvDigest member of the union,
+                // so it is written through a pointer to the structure.
+                let mut tpmu_attest = TPMU_ATTEST::default();
+                unsafe {
+                    *(std::ptr::addr_of_mut!(tpmu_attest) as *mut TPMS_NV_DIGEST_CERTIFY_INFO) =
+                        info.into();
+                }
+                tpmu_attest
+            }
         }
     }
 }
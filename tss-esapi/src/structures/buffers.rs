@@ -101,6 +101,7 @@ pub mod private;
 pub mod public;
 pub mod sensitive;
 pub mod sensitive_create;
+pub mod template;
 
 pub mod auth {
     buffer_type!(Auth, 64, TPM2B_AUTH);
@@ -219,6 +220,10 @@ pub mod encrypted_secret {
     named_field_buffer_type!(EncryptedSecret, 256, TPM2B_ENCRYPTED_SECRET, secret);
 }
 
+pub mod event {
+    buffer_type!(Event, 1024, TPM2B_EVENT);
+}
+
 pub mod id_object {
     named_field_buffer_type!(IdObject, 256, TPM2B_ID_OBJECT, credential);
 }
@@ -249,6 +254,10 @@ pub mod nonce {
     buffer_type!(Nonce, 64, TPM2B_NONCE);
 }
 
+pub mod operand {
+    buffer_type!(Operand, 64, TPM2B_OPERAND);
+}
+
 pub mod private_key_rsa {
     use crate::tss2_esys::TPM2_MAX_RSA_KEY_BYTES;
 
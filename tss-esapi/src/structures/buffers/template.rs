@@ -0,0 +1,123 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    structures::{Derive, Public},
+    traits::Marshall,
+    tss2_esys::{TPM2B_TEMPLATE, UINT16},
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::{convert::TryFrom, mem::size_of, ops::Deref};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Template buffer.
+///
+/// # Details
+/// Corresponds to `TPM2B_TEMPLATE`. The buffer contains a marshalled
+/// [Public] structure that is used as the template of an object
+/// created with [Context::create_loaded](crate::Context::create_loaded).
+///
+/// When the object is derived from a derivation parent the unique
+/// field of the template is replaced by a [Derive] structure, see
+/// [TemplateBuffer::with_derive].
+#[derive(Debug, Clone, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct TemplateBuffer(Vec<u8>);
+
+impl TemplateBuffer {
+    pub const MAX_SIZE: usize = size_of::<TPM2B_TEMPLATE>() - size_of::<UINT16>();
+
+    /// Creates a template for an object that is derived from a derivation parent.
+    ///
+    /// # Details
+    /// The unique field of the `public` structure is replaced by the
+    /// label and context in `derive`.
+    ///
+    /// # Errors
+    /// * if the resulting template is larger than [TemplateBuffer::MAX_SIZE]
+    ///   a `WrongParamSize` wrapper error is returned.
+    pub fn with_derive(public: Public, derive: Derive) -> Result<Self> {
+        let unique_size = match &public {
+            Public::Rsa { unique, .. } => size_of::<UINT16>() + unique.len(),
+            Public::KeyedHash { unique, .. } | Public::SymCipher { unique, .. } => {
+                size_of::<UINT16>() + unique.len()
+            }
+            Public::Ecc { unique, .. } => {
+                2 * size_of::<UINT16>() + unique.x().len() + unique.y().len()
+            }
+        };
+        let mut template = public.marshall()?;
+        // The unique field is the last field of the marshalled structure.
+        template.truncate(template.len() - unique_size);
+        template.extend_from_slice(&derive.marshall()?);
+        TemplateBuffer::try_from(template)
+    }
+
+    pub fn value(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Private function for ensuring that a buffer size is valid.
+    fn ensure_valid_buffer_size(buffer_size: usize, container_name: &str) -> Result<()> {
+        if buffer_size > Self::MAX_SIZE {
+            error!("Invalid {} size(> {})", container_name, Self::MAX_SIZE);
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+        Ok(())
+    }
+}
+
+impl Deref for TemplateBuffer {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TryFrom<Vec<u8>> for TemplateBuffer {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self> {
+        Self::ensure_valid_buffer_size(bytes.len(), "Vec<u8>")?;
+        Ok(TemplateBuffer(bytes))
+    }
+}
+
+impl TryFrom<&[u8]> for TemplateBuffer {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::ensure_valid_buffer_size(bytes.len(), "&[u8]")?;
+        Ok(TemplateBuffer(bytes.to_vec()))
+    }
+}
+
+impl TryFrom<TPM2B_TEMPLATE> for TemplateBuffer {
+    type Error = Error;
+
+    fn try_from(tss: TPM2B_TEMPLATE) -> Result<Self> {
+        let size = tss.size as usize;
+        Self::ensure_valid_buffer_size(size, "buffer")?;
+        Ok(TemplateBuffer(tss.buffer[..size].to_vec()))
+    }
+}
+
+impl From<TemplateBuffer> for TPM2B_TEMPLATE {
+    fn from(native: TemplateBuffer) -> Self {
+        let mut buffer = TPM2B_TEMPLATE {
+            size: native.0.len() as u16,
+            ..Default::default()
+        };
+        buffer.buffer[..native.0.len()].copy_from_slice(&native.0);
+        buffer
+    }
+}
+
+impl TryFrom<Public> for TemplateBuffer {
+    type Error = Error;
+
+    fn try_from(public: Public) -> Result<TemplateBuffer> {
+        TemplateBuffer::try_from(public.marshall()?)
+    }
+}
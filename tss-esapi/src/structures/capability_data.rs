@@ -1,5 +1,7 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_act)]
+use crate::structures::ActDataList;
 use crate::{
     constants::CapabilityType,
     structures::{
@@ -32,7 +34,10 @@ pub enum CapabilityData {
     EccCurves(EccCurveList),
     // These are in the TPM TPMU_CAPABILITIES, but are not defined by esapi-2.4.1
     // AuthPolicies(),
-    // ActData(),
+    // The actData member of the TPMU_CAPABILITIES union was
+    // first added in the 3.0.0 version of the tpm2-tss
+    #[cfg(has_esys_act)]
+    ActData(ActDataList),
 }
 
 pub const fn max_cap_size<T>() -> usize {
@@ -96,6 +101,13 @@ impl From<CapabilityData> for TPMS_CAPABILITY_DATA {
                     eccCurves: data.into(),
                 },
             },
+            #[cfg(has_esys_act)]
+            CapabilityData::ActData(data) => TPMS_CAPABILITY_DATA {
+                capability: CapabilityType::Act.into(),
+                data: TPMU_CAPABILITIES {
+                    actData: data.into(),
+                },
+            },
         }
     }
 }
@@ -138,6 +150,11 @@ impl TryFrom<TPMS_CAPABILITY_DATA> for CapabilityData {
                 error!("AuthPolicies capability type is currently not supported");
                 Err(Error::WrapperError(WrapperErrorKind::UnsupportedParam))
             }
+            #[cfg(has_esys_act)]
+            CapabilityType::Act => Ok(CapabilityData::ActData(
+                unsafe { tpms_capability_data.data.actData }.try_into()?,
+            )),
+            #[cfg(not(has_esys_act))]
             CapabilityType::Act => {
                 error!("Act capability type is currently not supported");
                 Err(Error::WrapperError(WrapperErrorKind::UnsupportedParam))
@@ -0,0 +1,142 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+#[cfg(not(has_tpms_derive))]
+use crate::traits::Marshall;
+#[cfg(has_tpms_derive)]
+use crate::{
+    traits::impl_mu_standard,
+    tss2_esys::{TPM2B_LABEL, TPMS_DERIVE},
+};
+use crate::{tss2_esys::TPM2_LABEL_MAX_BUFFER, Error, Result, WrapperErrorKind};
+use log::error;
+#[cfg(has_tpms_derive)]
+use std::convert::TryFrom;
+#[cfg(not(has_tpms_derive))]
+use std::mem::size_of;
+
+/// Structure holding the label and context that are used
+/// when an object is derived from a derivation parent.
+///
+/// # Details
+/// This corresponds to TPMS_DERIVE. It is used in place of the
+/// unique field of a [TemplateBuffer](crate::structures::TemplateBuffer)
+/// when creating a derived object.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Derive {
+    label: Vec<u8>,
+    context: Vec<u8>,
+}
+
+impl Derive {
+    /// The maximum size of the label and the context.
+    ///
+    /// # Details
+    /// This corresponds to the size of the buffer in TPM2B_LABEL.
+    pub const MAX_LABEL_SIZE: usize = TPM2_LABEL_MAX_BUFFER as usize;
+
+    /// Creates a new Derive structure.
+    ///
+    /// # Errors
+    /// * if either the `label` or the `context` is larger than
+    ///   [Derive::MAX_LABEL_SIZE] a `WrongParamSize` wrapper error is returned.
+    pub fn new(label: Vec<u8>, context: Vec<u8>) -> Result<Self> {
+        Self::ensure_valid_label_size(label.len(), "label")?;
+        Self::ensure_valid_label_size(context.len(), "context")?;
+        Ok(Derive { label, context })
+    }
+
+    /// Returns the label.
+    pub fn label(&self) -> &[u8] {
+        &self.label
+    }
+
+    /// Returns the context.
+    pub fn context(&self) -> &[u8] {
+        &self.context
+    }
+
+    /// Private function for ensuring that a label size is valid.
+    fn ensure_valid_label_size(label_size: usize, label_name: &str) -> Result<()> {
+        if label_size > Self::MAX_LABEL_SIZE {
+            error!("Invalid {} size(> {})", label_name, Self::MAX_LABEL_SIZE);
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+        Ok(())
+    }
+}
+
+// The TPMS_DERIVE structure was first added in the 4.0.0 version of the tpm2-tss
+// and is missing from the bundled bindings, so the structure is marshalled by
+// the crate when the bindings lack it.
+#[cfg(has_tpms_derive)]
+impl From<Derive> for TPMS_DERIVE {
+    fn from(derive: Derive) -> Self {
+        TPMS_DERIVE {
+            label: label_to_tss(&derive.label),
+            context: label_to_tss(&derive.context),
+        }
+    }
+}
+
+#[cfg(has_tpms_derive)]
+impl TryFrom<TPMS_DERIVE> for Derive {
+    type Error = Error;
+
+    fn try_from(tpms_derive: TPMS_DERIVE) -> Result<Self> {
+        Derive::new(
+            label_from_tss(&tpms_derive.label, "label")?,
+            label_from_tss(&tpms_derive.context, "context")?,
+        )
+    }
+}
+
+// Implement marshalling traits.
+#[cfg(has_tpms_derive)]
+impl_mu_standard!(Derive, TPMS_DERIVE);
+
+/// Converts a label into a TPM2B_LABEL.
+#[cfg(has_tpms_derive)]
+fn label_to_tss(label: &[u8]) -> TPM2B_LABEL {
+    let mut tpm2b_label = TPM2B_LABEL {
+        // The size of the label has been validated when the structure was created.
+        size: label.len() as u16,
+        ..Default::default()
+    };
+    tpm2b_label.buffer[..label.len()].copy_from_slice(label);
+    tpm2b_label
+}
+
+/// Converts a TPM2B_LABEL into a label.
+#[cfg(has_tpms_derive)]
+fn label_from_tss(tpm2b_label: &TPM2B_LABEL, label_name: &str) -> Result<Vec<u8>> {
+    tpm2b_label
+        .buffer
+        .get(..usize::from(tpm2b_label.size))
+        .map(|label| label.to_vec())
+        .ok_or_else(|| {
+            error!("Invalid {} size(> {})", label_name, Derive::MAX_LABEL_SIZE);
+            Error::local_error(WrapperErrorKind::WrongParamSize)
+        })
+}
+
+#[cfg(not(has_tpms_derive))]
+impl Marshall for Derive {
+    const BUFFER_SIZE: usize = 2 * (size_of::<u16>() + Self::MAX_LABEL_SIZE);
+
+    fn marshall_offset(&self, marshalled_data: &mut [u8], offset: &mut usize) -> Result<()> {
+        for label in [&self.label, &self.context] {
+            // The size of the label has been validated when the structure was created.
+            let size = (label.len() as u16).to_be_bytes();
+            let start = *offset;
+            let end = start + size.len() + label.len();
+            let destination = marshalled_data.get_mut(start..end).ok_or_else(|| {
+                error!("Insufficient space in buffer to marshall Derive");
+                Error::local_error(WrapperErrorKind::WrongParamSize)
+            })?;
+            destination[..size.len()].copy_from_slice(&size);
+            destination[size.len()..].copy_from_slice(label);
+            *offset = end;
+        }
+        Ok(())
+    }
+}
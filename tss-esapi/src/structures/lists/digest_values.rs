@@ -3,8 +3,9 @@
 use crate::interface_types::algorithm::HashingAlgorithm;
 use crate::structures::Digest;
 use crate::structures::HashAgile;
-use crate::tss2_esys::TPML_DIGEST_VALUES;
-use crate::{Error, Result};
+use crate::tss2_esys::{TPM2_NUM_PCR_BANKS, TPML_DIGEST_VALUES};
+use crate::{Error, Result, WrapperErrorKind};
+use log::error;
 use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 
@@ -14,6 +15,8 @@ pub struct DigestValues {
 }
 
 impl DigestValues {
+    pub const MAX_SIZE: usize = TPM2_NUM_PCR_BANKS as usize;
+
     pub fn new() -> Self {
         DigestValues {
             digests: HashMap::new(),
@@ -23,6 +26,21 @@ impl DigestValues {
     pub fn set(&mut self, alg: HashingAlgorithm, dig: Digest) {
         let _ = self.digests.insert(alg, dig);
     }
+
+    /// Returns the digest associated with the hashing algorithm, if any.
+    pub fn get(&self, alg: HashingAlgorithm) -> Option<&Digest> {
+        self.digests.get(&alg)
+    }
+
+    /// Returns the number of digests in the digest values.
+    pub fn len(&self) -> usize {
+        self.digests.len()
+    }
+
+    /// Indicates if the digest values contains any digests.
+    pub fn is_empty(&self) -> bool {
+        self.digests.is_empty()
+    }
 }
 
 impl TryFrom<DigestValues> for TPML_DIGEST_VALUES {
@@ -38,3 +56,25 @@ impl TryFrom<DigestValues> for TPML_DIGEST_VALUES {
         Ok(tss_digest_values)
     }
 }
+
+impl TryFrom<TPML_DIGEST_VALUES> for DigestValues {
+    type Error = Error;
+    fn try_from(tpml_digest_values: TPML_DIGEST_VALUES) -> Result<Self> {
+        let digests_count = tpml_digest_values.count as usize;
+
+        if digests_count > DigestValues::MAX_SIZE {
+            error!(
+                "Invalid TPML_DIGEST_VALUES count(> {})",
+                DigestValues::MAX_SIZE
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+
+        let mut digest_values = DigestValues::new();
+        for &tpmt_ha in tpml_digest_values.digests[..digests_count].iter() {
+            let hash_agile = HashAgile::try_from(tpmt_ha)?;
+            digest_values.set(hash_agile.hashing_algorithm(), hash_agile.digest().clone());
+        }
+        Ok(digest_values)
+    }
+}
@@ -1,5 +1,9 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+pub mod ac_capability;
+#[cfg(has_esys_act)]
+pub mod act_data;
+pub mod algorithm;
 pub mod algorithm_property;
 pub mod command_code;
 pub mod command_code_attributes;
@@ -28,20 +28,24 @@ pub use names::name::Name;
 /// The result section
 /////////////////////////////////////////////////////////
 mod result;
+pub use result::CommitResult;
 pub use result::CreateKeyResult;
 pub use result::CreatePrimaryKeyResult;
+pub use result::PcrAllocateResult;
 /////////////////////////////////////////////////////////
 /// The sized buffers section
 /////////////////////////////////////////////////////////
 mod buffers;
 pub use self::buffers::{
     attest::AttestBuffer, auth::Auth, data::Data, digest::Digest, ecc_parameter::EccParameter,
-    encrypted_secret::EncryptedSecret, id_object::IdObject, initial_value::InitialValue,
-    max_buffer::MaxBuffer, max_nv_buffer::MaxNvBuffer, nonce::Nonce, private::Private,
-    private_key_rsa::PrivateKeyRsa, private_vendor_specific::PrivateVendorSpecific,
-    public::PublicBuffer, public_key_rsa::PublicKeyRsa, sensitive::SensitiveBuffer,
+    encrypted_secret::EncryptedSecret, event::Event, id_object::IdObject,
+    initial_value::InitialValue, max_buffer::MaxBuffer, max_nv_buffer::MaxNvBuffer, nonce::Nonce,
+    operand::Operand, private::Private, private_key_rsa::PrivateKeyRsa,
+    private_vendor_specific::PrivateVendorSpecific, public::PublicBuffer,
+    public_key_rsa::PublicKeyRsa, sensitive::SensitiveBuffer,
     sensitive_create::SensitiveCreateBuffer, sensitive_data::SensitiveData,
-    symmetric_key::SymmetricKey, timeout::Timeout, tpm_context_data::TpmContextData,
+    symmetric_key::SymmetricKey, template::TemplateBuffer, timeout::Timeout,
+    tpm_context_data::TpmContextData,
 };
 /////////////////////////////////////////////////////////
 /// The creation section
@@ -49,6 +53,11 @@ pub use self::buffers::{
 mod creation;
 pub use self::creation::CreationData;
 /////////////////////////////////////////////////////////
+/// The derive section
+/////////////////////////////////////////////////////////
+mod derive;
+pub use self::derive::Derive;
+/////////////////////////////////////////////////////////
 /// The hash section
 /////////////////////////////////////////////////////////
 mod hash;
@@ -107,6 +116,11 @@ pub mod pcr_selection_list {
     pub use super::lists::pcr_selection::*;
 }
 
+pub use self::algorithm_list::AlgorithmList;
+pub mod algorithm_list {
+    pub use super::lists::algorithm::*;
+}
+
 pub use self::command_code_list::CommandCodeList;
 pub mod command_code_list {
     pub use super::lists::command_code::*;
@@ -127,6 +141,18 @@ pub mod tagged_pcr_property_list {
     pub use super::lists::tagged_pcr_property::*;
 }
 
+#[cfg(has_esys_act)]
+pub use self::act_data_list::ActDataList;
+#[cfg(has_esys_act)]
+pub mod act_data_list {
+    pub use super::lists::act_data::*;
+}
+
+pub use self::ac_capability_list::AcCapabilityList;
+pub mod ac_capability_list {
+    pub use super::lists::ac_capability::*;
+}
+
 pub use self::command_code_attributes_list::CommandCodeAttributesList;
 pub mod command_code_attributes_list {
     pub use super::lists::command_code_attributes::*;
@@ -172,7 +198,7 @@ pub use tagged::{
 /// ECC structures
 /////////////////////////////////////////////////////////
 mod ecc;
-pub use ecc::point::EccPoint;
+pub use ecc::{algorithm_detail::AlgorithmDetailEcc, point::EccPoint};
 /////////////////////////////////////////////////////////
 /// Signatures structures
 /////////////////////////////////////////////////////////
@@ -197,6 +223,8 @@ pub use clock::{clock_info::ClockInfo, time_info::TimeInfo};
 /// Property Structures
 /////////////////////////////////////////////////////////
 mod property;
+#[cfg(has_esys_act)]
+pub use property::act_data::ActData;
 pub use property::{
     algorithm_property::AlgorithmProperty, tagged_pcr_select::TaggedPcrSelect,
     tagged_property::TaggedProperty,
@@ -213,6 +241,11 @@ pub use nv::storage::{NvPublic, NvPublicBuilder};
 mod algorithm;
 pub use algorithm::symmetric::sensitive_create::SensitiveCreate;
 /////////////////////////////////////////////////////////
+/// Attached component structures
+/////////////////////////////////////////////////////////
+mod ac;
+pub use ac::output::AcOutput;
+/////////////////////////////////////////////////////////
 /// TPM context structures
 /////////////////////////////////////////////////////////
 mod tpm_context;
@@ -1,5 +1,7 @@
 // Copyright 2022 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_act)]
+pub mod act_data;
 pub mod algorithm_property;
 pub mod tagged_pcr_select;
 pub mod tagged_property;
@@ -3,7 +3,7 @@
 
 use crate::{
     handles::KeyHandle,
-    structures::{CreationData, CreationTicket, Digest, Private, Public},
+    structures::{CreationData, CreationTicket, Digest, EccPoint, Private, Public},
 };
 
 #[allow(missing_debug_implementations)]
@@ -23,3 +23,35 @@ pub struct CreatePrimaryKeyResult {
     pub creation_hash: Digest,
     pub creation_ticket: CreationTicket,
 }
+
+/// The result of a PCR allocation.
+///
+/// # Details
+/// The new allocation takes effect after the next TPM reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcrAllocateResult {
+    /// Whether the allocation succeeded.
+    pub allocation_success: bool,
+    /// The maximum number of PCRs that may be in a bank.
+    pub max_pcr: u32,
+    /// The number of octets required to satisfy the request.
+    pub size_needed: u32,
+    /// The number of octets available.
+    pub size_available: u32,
+}
+
+/// The result of a commit for an anonymous signing operation.
+///
+/// # Details
+/// The points that the TPM did not compute are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitResult {
+    /// The point `K`.
+    pub k: Option<EccPoint>,
+    /// The point `L`.
+    pub l: Option<EccPoint>,
+    /// The point `E`.
+    pub e: Option<EccPoint>,
+    /// The counter identifying the ephemeral value.
+    pub counter: u16,
+}
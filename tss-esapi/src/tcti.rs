@@ -0,0 +1,408 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Interface for implementing a TCTI in Rust.
+//!
+//! A type implementing the [Tcti] trait can be used as the transport of a
+//! [Context](crate::Context), see [Context::new_with_tcti](crate::Context::new_with_tcti).
+//! This allows the commands to be sent to the TPM over channels for which no
+//! TCTI module is available, for example a proxy or an in-process simulator.
+
+use crate::{
+    constants::tss::{
+        TSS2_BASE_RC_BAD_CONTEXT, TSS2_BASE_RC_BAD_REFERENCE, TSS2_BASE_RC_GENERAL_FAILURE,
+        TSS2_BASE_RC_INSUFFICIENT_BUFFER, TSS2_BASE_RC_NOT_IMPLEMENTED, TSS2_BASE_RC_TRY_AGAIN,
+        TSS2_RC_SUCCESS, TSS2_TCTI_RC_LAYER,
+    },
+    tss2_esys::{
+        size_t, TSS2_RC, TSS2_TCTI_CONTEXT, TSS2_TCTI_CONTEXT_COMMON_V1, TSS2_TCTI_TIMEOUT_BLOCK,
+    },
+    Error, Result, ReturnCode,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+/// Value identifying the TCTI contexts created by this crate.
+const MAGIC: u64 = 0x7275_7374_7463_7469;
+
+/// Version of the common TCTI context structure.
+const VERSION: u32 = 1;
+
+/// Trait for types that transmit the commands to the TPM
+/// and receive the responses.
+///
+/// # Details
+/// The methods mirror the functions of the TCTI specification. The commands
+/// and responses are fully marshalled, the implementation only needs to
+/// transport them. Errors are reported to the ESAPI using the response code
+/// of [Error::TssError] or, for wrapper errors, a general TCTI failure.
+pub trait Tcti: Send {
+    /// Transmits a command to the TPM.
+    ///
+    /// # Arguments
+    /// * `command` - The marshalled command.
+    fn transmit(&mut self, command: &[u8]) -> Result<()>;
+
+    /// Receives the response to the last transmitted command.
+    ///
+    /// # Arguments
+    /// * `timeout` - The time to wait for the response, `None` means
+    ///               that the method blocks until the response is available.
+    ///
+    /// # Returns
+    /// The marshalled response or `None` if the response did
+    /// not become available within the timeout.
+    fn receive(&mut self, timeout: Option<Duration>) -> Result<Option<Vec<u8>>>;
+
+    /// Cancels the command that is being executed by the TPM.
+    ///
+    /// # Details
+    /// The default implementation returns a `NotImplemented` TCTI error.
+    fn cancel(&mut self) -> Result<()> {
+        Err(not_implemented())
+    }
+
+    /// Sets the locality of the following commands.
+    ///
+    /// # Arguments
+    /// * `locality` - The locality, values from 0 to 4 are the
+    ///                localities and values from 32 to 255 are the
+    ///                extended localities.
+    ///
+    /// # Details
+    /// The default implementation returns a `NotImplemented` TCTI error.
+    fn set_locality(&mut self, locality: u8) -> Result<()> {
+        let _ = locality;
+        Err(not_implemented())
+    }
+}
+
+/// Creates the error returned by the methods that are not implemented.
+fn not_implemented() -> Error {
+    match ReturnCode::try_from(TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_NOT_IMPLEMENTED) {
+        Ok(return_code) => Error::tss_error(return_code),
+        Err(error) => error,
+    }
+}
+
+/// TCTI context that dispatches the calls of the ESAPI to a [Tcti].
+#[repr(C)]
+struct TctiShim {
+    /// The common structure which all TCTI contexts start with.
+    common: TSS2_TCTI_CONTEXT_COMMON_V1,
+    tcti: Box<dyn Tcti>,
+    /// Response that has been received but not yet
+    /// been copied to the buffer of the caller.
+    response: Option<Vec<u8>>,
+}
+
+/// TCTI context backed by a [Tcti].
+pub(crate) struct CustomTctiContext {
+    /// The shim, allocated by the context. It is only accessed through
+    /// this pointer, which is also the one handed to the ESAPI.
+    shim: *mut TctiShim,
+}
+
+impl CustomTctiContext {
+    /// Creates a new TCTI context that uses the provided [Tcti].
+    pub(crate) fn new<T: Tcti + 'static>(tcti: T) -> Self {
+        CustomTctiContext {
+            shim: Box::into_raw(Box::new(TctiShim {
+                common: TSS2_TCTI_CONTEXT_COMMON_V1 {
+                    magic: MAGIC,
+                    version: VERSION,
+                    transmit: Some(transmit),
+                    receive: Some(receive),
+                    finalize: Some(finalize),
+                    cancel: Some(cancel),
+                    getPollHandles: None,
+                    setLocality: Some(set_locality),
+                },
+                tcti: Box::new(tcti),
+                response: None,
+            })),
+        }
+    }
+
+    /// Get access to the inner C pointer
+    pub(crate) fn tcti_context_ptr(&mut self) -> *mut TSS2_TCTI_CONTEXT {
+        self.shim.cast()
+    }
+}
+
+impl Drop for CustomTctiContext {
+    fn drop(&mut self) {
+        // The pointer was created by `Box::into_raw` and is released only here.
+        drop(unsafe { Box::from_raw(self.shim) });
+    }
+}
+
+impl std::fmt::Debug for CustomTctiContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomTctiContext").finish_non_exhaustive()
+    }
+}
+
+// `Send` is implemented as the shim, and with it the `Tcti` which is `Send`,
+// is owned by the context. `Sync` is implemented so that the `Context` stays
+// `Sync` like with the `TctiContext` of the TCTI loader. It is safe as the
+// shim can only be accessed through methods that require a `&mut self`.
+unsafe impl Send for CustomTctiContext {}
+unsafe impl Sync for CustomTctiContext {}
+
+/// Converts the result of a [Tcti] method into a return code.
+fn tss2_rc(result: Result<()>) -> TSS2_RC {
+    match result {
+        Ok(()) => TSS2_RC_SUCCESS,
+        Err(Error::TssError(return_code)) => return_code.into(),
+        Err(error) => {
+            error!("Error in TCTI: {}", error);
+            TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_GENERAL_FAILURE
+        }
+    }
+}
+
+/// Calls the function with the shim of the TCTI context,
+/// converting panics into general failures.
+fn with_shim<F>(tcti_context: *mut TSS2_TCTI_CONTEXT, f: F) -> TSS2_RC
+where
+    F: FnOnce(&mut TctiShim) -> TSS2_RC,
+{
+    if tcti_context.is_null() {
+        return TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_BAD_REFERENCE;
+    }
+    // The magic is part of the common structure, so it
+    // can be checked before the shim is accessed.
+    if unsafe { (*(tcti_context as *const TSS2_TCTI_CONTEXT_COMMON_V1)).magic } != MAGIC {
+        return TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_BAD_CONTEXT;
+    }
+    let shim = unsafe { &mut *(tcti_context as *mut TctiShim) };
+    panic::catch_unwind(AssertUnwindSafe(|| f(shim))).unwrap_or_else(|_| {
+        error!("The TCTI panicked");
+        TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_GENERAL_FAILURE
+    })
+}
+
+unsafe extern "C" fn transmit(
+    tcti_context: *mut TSS2_TCTI_CONTEXT,
+    size: size_t,
+    command: *const u8,
+) -> TSS2_RC {
+    with_shim(tcti_context, |shim| {
+        if command.is_null() {
+            return TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_BAD_REFERENCE;
+        }
+        let command = std::slice::from_raw_parts(command, size as usize);
+        shim.response = None;
+        tss2_rc(shim.tcti.transmit(command))
+    })
+}
+
+unsafe extern "C" fn receive(
+    tcti_context: *mut TSS2_TCTI_CONTEXT,
+    size: *mut size_t,
+    response: *mut u8,
+    timeout: i32,
+) -> TSS2_RC {
+    with_shim(tcti_context, |shim| {
+        if size.is_null() {
+            return TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_BAD_REFERENCE;
+        }
+        if shim.response.is_none() {
+            let timeout = if timeout == TSS2_TCTI_TIMEOUT_BLOCK {
+                None
+            } else {
+                Some(Duration::from_millis(timeout.max(0) as u64))
+            };
+            match shim.tcti.receive(timeout) {
+                Ok(Some(received)) => shim.response = Some(received),
+                Ok(None) => return TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_TRY_AGAIN,
+                Err(error) => return tss2_rc(Err(error)),
+            }
+        }
+        let received = shim.response.as_deref().unwrap_or_default();
+        // A null buffer is used to query the size of the response.
+        if response.is_null() {
+            *size = received.len() as size_t;
+            return TSS2_RC_SUCCESS;
+        }
+        if (*size as usize) < received.len() {
+            return TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_INSUFFICIENT_BUFFER;
+        }
+        std::ptr::copy_nonoverlapping(received.as_ptr(), response, received.len());
+        *size = received.len() as size_t;
+        shim.response = None;
+        TSS2_RC_SUCCESS
+    })
+}
+
+unsafe extern "C" fn finalize(_tcti_context: *mut TSS2_TCTI_CONTEXT) {
+    // The shim is owned and released by the CustomTctiContext.
+}
+
+unsafe extern "C" fn cancel(tcti_context: *mut TSS2_TCTI_CONTEXT) -> TSS2_RC {
+    with_shim(tcti_context, |shim| tss2_rc(shim.tcti.cancel()))
+}
+
+unsafe extern "C" fn set_locality(tcti_context: *mut TSS2_TCTI_CONTEXT, locality: u8) -> TSS2_RC {
+    with_shim(tcti_context, |shim| {
+        tss2_rc(shim.tcti.set_locality(locality))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Calls made to the [Tcti].
+    #[derive(Debug, PartialEq, Eq)]
+    enum Call {
+        Cancel,
+        SetLocality(u8),
+    }
+
+    /// TCTI that records the calls, answers with a fixed response and
+    /// panics when a command is transmitted.
+    struct TestTcti {
+        calls: Arc<Mutex<Vec<Call>>>,
+        response: Vec<u8>,
+    }
+
+    impl Tcti for TestTcti {
+        fn transmit(&mut self, _command: &[u8]) -> Result<()> {
+            panic!("Failed to transmit the command");
+        }
+
+        fn receive(&mut self, _timeout: Option<Duration>) -> Result<Option<Vec<u8>>> {
+            Ok(Some(self.response.clone()))
+        }
+
+        fn cancel(&mut self) -> Result<()> {
+            self.calls.lock().unwrap().push(Call::Cancel);
+            Ok(())
+        }
+
+        fn set_locality(&mut self, locality: u8) -> Result<()> {
+            self.calls.lock().unwrap().push(Call::SetLocality(locality));
+            Ok(())
+        }
+    }
+
+    /// Creates a TCTI context and returns the calls recorded by its [Tcti].
+    fn test_tcti_context(response: Vec<u8>) -> (CustomTctiContext, Arc<Mutex<Vec<Call>>>) {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let tcti_context = CustomTctiContext::new(TestTcti {
+            calls: calls.clone(),
+            response,
+        });
+        (tcti_context, calls)
+    }
+
+    /// Returns the common structure through which the ESAPI calls the TCTI.
+    fn common(tcti_context: &mut CustomTctiContext) -> TSS2_TCTI_CONTEXT_COMMON_V1 {
+        unsafe {
+            *tcti_context
+                .tcti_context_ptr()
+                .cast::<TSS2_TCTI_CONTEXT_COMMON_V1>()
+        }
+    }
+
+    #[test]
+    fn test_cancel_and_set_locality() {
+        let (mut tcti_context, calls) = test_tcti_context(Vec::new());
+        let common = common(&mut tcti_context);
+        let tcti_context_ptr = tcti_context.tcti_context_ptr();
+
+        assert_eq!(TSS2_RC_SUCCESS, unsafe {
+            common.cancel.expect("cancel is missing")(tcti_context_ptr)
+        });
+        assert_eq!(TSS2_RC_SUCCESS, unsafe {
+            common.setLocality.expect("setLocality is missing")(tcti_context_ptr, 3)
+        });
+        assert_eq!(
+            vec![Call::Cancel, Call::SetLocality(3)],
+            *calls.lock().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_not_implemented() {
+        struct MinimalTcti;
+
+        impl Tcti for MinimalTcti {
+            fn transmit(&mut self, _command: &[u8]) -> Result<()> {
+                Ok(())
+            }
+
+            fn receive(&mut self, _timeout: Option<Duration>) -> Result<Option<Vec<u8>>> {
+                Ok(None)
+            }
+        }
+
+        let mut tcti_context = CustomTctiContext::new(MinimalTcti);
+        let common = common(&mut tcti_context);
+        let tcti_context_ptr = tcti_context.tcti_context_ptr();
+
+        assert_eq!(TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_NOT_IMPLEMENTED, unsafe {
+            common.cancel.expect("cancel is missing")(tcti_context_ptr)
+        });
+        assert_eq!(TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_NOT_IMPLEMENTED, unsafe {
+            common.setLocality.expect("setLocality is missing")(tcti_context_ptr, 3)
+        });
+    }
+
+    #[test]
+    fn test_panic() {
+        let (mut tcti_context, _) = test_tcti_context(Vec::new());
+        let common = common(&mut tcti_context);
+        let command = [0x80, 0x01, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x00, 0x01, 0x7B];
+
+        assert_eq!(TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_GENERAL_FAILURE, unsafe {
+            common.transmit.expect("transmit is missing")(
+                tcti_context.tcti_context_ptr(),
+                command.len() as size_t,
+                command.as_ptr(),
+            )
+        });
+    }
+
+    #[test]
+    fn test_insufficient_buffer() {
+        let response = vec![0x80, 0x01, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x00];
+        let (mut tcti_context, _) = test_tcti_context(response.clone());
+        let common = common(&mut tcti_context);
+        let receive = common.receive.expect("receive is missing");
+        let tcti_context_ptr = tcti_context.tcti_context_ptr();
+
+        let mut buffer = vec![0u8; response.len() - 1];
+        let mut size = buffer.len() as size_t;
+        assert_eq!(
+            TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_INSUFFICIENT_BUFFER,
+            unsafe {
+                receive(
+                    tcti_context_ptr,
+                    &mut size,
+                    buffer.as_mut_ptr(),
+                    TSS2_TCTI_TIMEOUT_BLOCK,
+                )
+            }
+        );
+
+        // The response is kept until it has been copied to a large enough buffer.
+        let mut buffer = vec![0u8; response.len()];
+        let mut size = buffer.len() as size_t;
+        assert_eq!(TSS2_RC_SUCCESS, unsafe {
+            receive(
+                tcti_context_ptr,
+                &mut size,
+                buffer.as_mut_ptr(),
+                TSS2_TCTI_TIMEOUT_BLOCK,
+            )
+        });
+        assert_eq!(response.len(), size as usize);
+        assert_eq!(response, buffer);
+    }
+}
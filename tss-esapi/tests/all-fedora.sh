@@ -43,9 +43,9 @@ tpm2-abrmd \
 ###################
 # Build the crate #
 ###################
-RUST_BACKTRACE=1 cargo build --features "generate-bindings integration-tests serde"
+RUST_BACKTRACE=1 cargo build --features "generate-bindings integration-tests serde async"
 
 #################
 # Run the tests #
 #################
-TEST_TCTI=tabrmd:bus_type=session RUST_BACKTRACE=1 RUST_LOG=info cargo test --features "generate-bindings integration-tests serde" --  --test-threads=1 --nocapture
+TEST_TCTI=tabrmd:bus_type=session RUST_BACKTRACE=1 RUST_LOG=info cargo test --features "generate-bindings integration-tests serde async" --  --test-threads=1 --nocapture
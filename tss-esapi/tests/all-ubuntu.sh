@@ -24,9 +24,9 @@ fi
 # Generate bindings for non-"standard" versions #
 #################################################
 if [[ "${TPM2_TSS_VERSION}" != "${TPM2_TSS_BINDINGS_VERSION}" ]]; then
-	FEATURES="generate-bindings integration-tests serde"
+	FEATURES="generate-bindings integration-tests serde async"
 else
-	FEATURES="integration-tests serde"
+	FEATURES="integration-tests serde async"
 fi
 
 if [[ ! -z ${TPM2_TSS_PATH:+x} ]]; then
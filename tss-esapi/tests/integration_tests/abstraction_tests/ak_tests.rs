@@ -193,6 +193,7 @@ fn test_create_and_use_ak() {
                 Default::default(),
                 Default::default(),
                 None,
+                false,
             )
         })
         .unwrap();
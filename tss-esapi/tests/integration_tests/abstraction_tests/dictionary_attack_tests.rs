@@ -0,0 +1,23 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use tss_esapi::{
+    abstraction::dictionary_attack, interface_types::reserved_handles::Lockout,
+    interface_types::session_handles::AuthSession,
+};
+
+use crate::common::create_ctx_without_session;
+
+#[test]
+fn get_status() {
+    let mut context = create_ctx_without_session();
+    context.set_sessions((Some(AuthSession::Password), None, None));
+    context
+        .dictionary_attack_lock_reset(Lockout::Lockout)
+        .expect("Failed to reset the lockout");
+
+    let status = dictionary_attack::get_status(&mut context).expect("Failed to get status");
+    assert_eq!(0, status.lockout_counter());
+    assert!(!status.in_lockout());
+    assert_eq!(status.max_auth_fail(), status.remaining_tries());
+}
@@ -0,0 +1,15 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use tss_esapi::{abstraction::field_upgrade, Error, WrapperErrorKind};
+
+use crate::common::create_ctx_without_session;
+
+#[test]
+fn field_upgrade_data_without_data() {
+    let mut context = create_ctx_without_session();
+    assert_eq!(
+        field_upgrade::field_upgrade_data(&mut context, &mut std::io::empty()),
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam))
+    );
+}
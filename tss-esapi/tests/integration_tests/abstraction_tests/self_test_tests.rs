@@ -0,0 +1,31 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::convert::TryFrom;
+use std::time::Duration;
+use tss_esapi::{
+    abstraction::self_test, constants::AlgorithmIdentifier, structures::AlgorithmList,
+};
+
+use crate::common::create_ctx_without_session;
+
+#[test]
+fn test_algorithms() {
+    let mut context = create_ctx_without_session();
+    let required = AlgorithmList::try_from(vec![
+        AlgorithmIdentifier::Rsa,
+        AlgorithmIdentifier::Sha256,
+        AlgorithmIdentifier::Aes,
+    ])
+    .expect("Failed to create algorithm list");
+
+    self_test::test_algorithms(&mut context, required.clone(), Duration::from_secs(10))
+        .expect("Failed to test algorithms");
+
+    let to_do_list = context
+        .incremental_self_test(required.clone())
+        .expect("Failed to call incremental_self_test");
+    assert!(required
+        .iter()
+        .all(|algorithm| !to_do_list.contains(algorithm)));
+}
@@ -0,0 +1,80 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::io::Write;
+use tss_esapi::{
+    abstraction::sequence::Sequence,
+    interface_types::{
+        algorithm::HashingAlgorithm, reserved_handles::Hierarchy, session_handles::AuthSession,
+    },
+    structures::{MaxBuffer, Ticket},
+};
+
+use crate::common::create_ctx_without_session;
+use sha2::{Digest as _, Sha256};
+
+#[test]
+fn hash_large_data() {
+    let mut context = create_ctx_without_session();
+    context.set_sessions((Some(AuthSession::Password), None, None));
+    let data: Vec<u8> = (0..(4 * MaxBuffer::MAX_SIZE + 100))
+        .map(|i| (i % 256) as u8)
+        .collect();
+
+    let mut sequence = Sequence::hash(&mut context, HashingAlgorithm::Sha256, None)
+        .expect("Failed to start hash sequence");
+    sequence
+        .write_all(&data)
+        .expect("Failed to write data to the sequence");
+    let (digest, ticket) = sequence
+        .complete(Hierarchy::Owner)
+        .expect("Failed to complete hash sequence");
+
+    assert_eq!(Sha256::digest(&data).as_slice(), digest.as_bytes());
+    assert_eq!(ticket.hierarchy(), Hierarchy::Owner);
+}
+
+#[test]
+fn event_sequence() {
+    let mut context = create_ctx_without_session();
+    context.set_sessions((Some(AuthSession::Password), None, None));
+    let data = vec![0x5a; 2 * MaxBuffer::MAX_SIZE];
+
+    let mut sequence = Sequence::event(&mut context, None).expect("Failed to start event sequence");
+    sequence
+        .write_all(&data)
+        .expect("Failed to write data to the sequence");
+    let digest_values = sequence
+        .complete_event(None)
+        .expect("Failed to complete event sequence");
+
+    assert_eq!(
+        Sha256::digest(&data).as_slice(),
+        digest_values
+            .get(HashingAlgorithm::Sha256)
+            .expect("Missing SHA256 digest")
+            .as_bytes()
+    );
+}
+
+#[test]
+fn dropped_sequence_is_flushed() {
+    let mut context = create_ctx_without_session();
+    context.set_sessions((Some(AuthSession::Password), None, None));
+
+    {
+        let mut sequence = Sequence::hash(&mut context, HashingAlgorithm::Sha256, None)
+            .expect("Failed to start hash sequence");
+        sequence
+            .write_all(&[1, 2, 3])
+            .expect("Failed to write data to the sequence");
+    }
+
+    // Starting and completing a new sequence still works once the previous one has
+    // been dropped.
+    let sequence = Sequence::hash(&mut context, HashingAlgorithm::Sha256, None)
+        .expect("Failed to start hash sequence");
+    let _ = sequence
+        .complete(Hierarchy::Null)
+        .expect("Failed to complete hash sequence");
+}
@@ -0,0 +1,97 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::common::{create_ctx_with_session, signing_key_pub};
+use picky_asn1_x509::{Certificate, Extensions, Name, SubjectPublicKeyInfo, Validity};
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::x509::{self, PartialCertificate},
+    interface_types::{reserved_handles::Hierarchy, session_handles::AuthSession},
+    structures::SignatureScheme,
+};
+
+// Name: CN=tss-esapi
+const NAME: [u8; 22] = [
+    0x30, 0x14, 0x31, 0x12, 0x30, 0x10, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x09, 0x74, 0x73, 0x73,
+    0x2d, 0x65, 0x73, 0x61, 0x70, 0x69,
+];
+// Validity: 2024-01-01 00:00:00 UTC to 2034-01-01 00:00:00 UTC
+const VALIDITY: [u8; 32] = [
+    0x30, 0x1e, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x5a, 0x17, 0x0d, 0x33, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a,
+];
+// Extensions: critical key usage with digitalSignature
+const EXTENSIONS: [u8; 18] = [
+    0x30, 0x10, 0x30, 0x0e, 0x06, 0x03, 0x55, 0x1d, 0x0f, 0x01, 0x01, 0xff, 0x04, 0x04, 0x03, 0x02,
+    0x07, 0x80,
+];
+
+fn partial_certificate() -> PartialCertificate {
+    let name: Name = picky_asn1_der::from_bytes(&NAME).expect("Failed to decode name");
+    let validity: Validity =
+        picky_asn1_der::from_bytes(&VALIDITY).expect("Failed to decode validity");
+    let extensions: Extensions =
+        picky_asn1_der::from_bytes(&EXTENSIONS).expect("Failed to decode extensions");
+    PartialCertificate::new(name.clone(), validity, name, extensions)
+}
+
+#[test]
+fn partial_certificate_to_der() {
+    let der = partial_certificate()
+        .to_der()
+        .expect("Failed to encode partial certificate");
+    let expected = [
+        &[0x30, 0x60][..],
+        &NAME,
+        &VALIDITY,
+        &NAME,
+        &[0xa3, 0x12],
+        &EXTENSIONS,
+    ]
+    .concat();
+    assert_eq!(expected, der);
+}
+
+#[test]
+fn certify_x509() {
+    let mut context = create_ctx_with_session();
+    let sign_key_handle = context
+        .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+        .unwrap()
+        .key_handle;
+    let key = context
+        .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+        .unwrap();
+
+    let partial_certificate = partial_certificate();
+    let der = context
+        .execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| {
+                x509::certify_x509(
+                    ctx,
+                    key.key_handle,
+                    sign_key_handle,
+                    SignatureScheme::Null,
+                    &partial_certificate,
+                )
+            },
+        )
+        .expect("Failed to create certificate");
+
+    let certificate: Certificate =
+        picky_asn1_der::from_bytes(&der).expect("Failed to decode certificate");
+    let tbs_certificate = &certificate.tbs_certificate;
+    assert_eq!(partial_certificate.issuer(), &tbs_certificate.issuer);
+    assert_eq!(partial_certificate.subject(), &tbs_certificate.subject);
+    assert_eq!(certificate.signature_algorithm, tbs_certificate.signature);
+    assert_eq!(
+        SubjectPublicKeyInfo::try_from(key.out_public)
+            .expect("Failed to convert public key")
+            .algorithm,
+        tbs_certificate.subject_public_key_info.algorithm
+    );
+}
@@ -0,0 +1,62 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use tss_esapi::{attributes::ActAttributes, tss2_esys::TPMA_ACT};
+
+#[test]
+fn test_conversions() {
+    let expected_tpma_act: TPMA_ACT = 0x2;
+    let expected_act_attributes = ActAttributes(expected_tpma_act);
+    let actual_act_attributes: ActAttributes = expected_tpma_act.into();
+    let actual_tpma_act: TPMA_ACT = expected_act_attributes.into();
+
+    assert_eq!(
+        expected_act_attributes, actual_act_attributes,
+        "ActAttributes converted from TPMA_ACT did not contain expected value"
+    );
+
+    assert_eq!(
+        expected_tpma_act, actual_tpma_act,
+        "TPMA_ACT converted from ActAttributes did not contain expected value"
+    );
+}
+
+#[test]
+fn test_all_set() {
+    let attributes = ActAttributes::from(0xFFFFFFFF);
+    assert!(attributes.signaled(), "'signaled' is unexpectedly not set");
+    assert!(
+        attributes.preserve_signaled(),
+        "'preserve_signaled' is unexpectedly not set"
+    );
+}
+
+#[test]
+fn test_none_set() {
+    let attributes = ActAttributes::from(0x0);
+    assert!(!attributes.signaled(), "'signaled' is unexpectedly set");
+    assert!(
+        !attributes.preserve_signaled(),
+        "'preserve_signaled' is unexpectedly set"
+    );
+}
+
+#[test]
+fn test_signaled_set() {
+    let attributes = ActAttributes::from(0x1);
+    assert!(attributes.signaled(), "'signaled' is unexpectedly not set");
+    assert!(
+        !attributes.preserve_signaled(),
+        "'preserve_signaled' is unexpectedly set"
+    );
+}
+
+#[test]
+fn test_preserve_signaled_set() {
+    let attributes = ActAttributes::from(0x2);
+    assert!(!attributes.signaled(), "'signaled' is unexpectedly set");
+    assert!(
+        attributes.preserve_signaled(),
+        "'preserve_signaled' is unexpectedly not set"
+    );
+}
@@ -263,6 +263,27 @@ pub fn signing_key_pub() -> Public {
     .expect("Failed to create an unrestricted signing rsa public structure")
 }
 
+#[allow(dead_code)]
+pub fn hmac_key_pub() -> Public {
+    let object_attributes = ObjectAttributesBuilder::new()
+        .with_sign_encrypt(true)
+        .with_sensitive_data_origin(true)
+        .with_user_with_auth(true)
+        .build()
+        .expect("Failed to build object attributes");
+
+    PublicBuilder::new()
+        .with_public_algorithm(PublicAlgorithm::KeyedHash)
+        .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+        .with_object_attributes(object_attributes)
+        .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
+            KeyedHashScheme::HMAC_SHA_256,
+        ))
+        .with_keyed_hash_unique_identifier(Default::default())
+        .build()
+        .expect("Failed to build public structure for key.")
+}
+
 #[allow(dead_code)]
 pub fn get_pcr_policy_digest(
     context: &mut Context,
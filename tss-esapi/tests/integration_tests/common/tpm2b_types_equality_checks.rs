@@ -1,8 +1,8 @@
 // Copyright 2022 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use tss_esapi::tss2_esys::{
-    TPM2B_AUTH, TPM2B_CONTEXT_DATA, TPM2B_DATA, TPM2B_DIGEST, TPM2B_MAX_NV_BUFFER, TPM2B_NAME,
-    TPM2B_SENSITIVE_CREATE, TPM2B_SENSITIVE_DATA,
+    TPM2B_AUTH, TPM2B_CONTEXT_DATA, TPM2B_DATA, TPM2B_DIGEST, TPM2B_ECC_PARAMETER,
+    TPM2B_MAX_NV_BUFFER, TPM2B_NAME, TPM2B_SENSITIVE_CREATE, TPM2B_SENSITIVE_DATA,
 };
 
 macro_rules! ensure_sized_buffer_equality {
@@ -67,3 +67,10 @@ pub fn ensure_tpm2b_context_data_equality(
 ) {
     ensure_sized_buffer_equality!(expected, actual, buffer, TPM2B_CONTEXT_DATA);
 }
+
+pub fn ensure_tpm2b_ecc_parameter_equality(
+    expected: &TPM2B_ECC_PARAMETER,
+    actual: &TPM2B_ECC_PARAMETER,
+) {
+    ensure_sized_buffer_equality!(expected, actual, buffer, TPM2B_ECC_PARAMETER);
+}
@@ -1,8 +1,10 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_act)]
+use tss_esapi::tss2_esys::TPML_ACT_DATA;
 use tss_esapi::tss2_esys::{
-    TPML_ALG_PROPERTY, TPML_CCA, TPML_PCR_SELECTION, TPML_TAGGED_PCR_PROPERTY,
-    TPML_TAGGED_TPM_PROPERTY,
+    TPML_AC_CAPABILITIES, TPML_ALG_PROPERTY, TPML_CCA, TPML_PCR_SELECTION,
+    TPML_TAGGED_PCR_PROPERTY, TPML_TAGGED_TPM_PROPERTY,
 };
 
 macro_rules! ensure_list_equality {
@@ -58,3 +60,18 @@ ensure_list_equality!(
     commandAttributes,
     ensure_tpma_cc_equality
 );
+
+ensure_list_equality!(
+    ensure_tpml_ac_capabilities_equality,
+    TPML_AC_CAPABILITIES,
+    acCapabilities,
+    ensure_tpms_ac_output_equality
+);
+
+#[cfg(has_esys_act)]
+ensure_list_equality!(
+    ensure_tpml_act_data_equality,
+    TPML_ACT_DATA,
+    actData,
+    ensure_tpms_act_data_equality
+);
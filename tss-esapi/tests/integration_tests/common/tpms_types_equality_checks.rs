@@ -1,17 +1,20 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_act)]
+use tss_esapi::tss2_esys::TPMS_ACT_DATA;
 use tss_esapi::{
     constants::tss::{
         TPM2_ST_ATTEST_CERTIFY, TPM2_ST_ATTEST_COMMAND_AUDIT, TPM2_ST_ATTEST_CREATION,
         TPM2_ST_ATTEST_NV, TPM2_ST_ATTEST_QUOTE, TPM2_ST_ATTEST_SESSION_AUDIT, TPM2_ST_ATTEST_TIME,
     },
     tss2_esys::{
-        TPMS_ALG_PROPERTY, TPMS_ATTEST, TPMS_CERTIFY_INFO, TPMS_CLOCK_INFO,
-        TPMS_COMMAND_AUDIT_INFO, TPMS_CONTEXT, TPMS_CREATION_INFO, TPMS_ECC_PARMS, TPMS_EMPTY,
-        TPMS_KEYEDHASH_PARMS, TPMS_NV_CERTIFY_INFO, TPMS_PCR_SELECTION, TPMS_QUOTE_INFO,
-        TPMS_RSA_PARMS, TPMS_SCHEME_ECDAA, TPMS_SCHEME_HASH, TPMS_SCHEME_HMAC, TPMS_SCHEME_XOR,
-        TPMS_SENSITIVE_CREATE, TPMS_SESSION_AUDIT_INFO, TPMS_SYMCIPHER_PARMS,
-        TPMS_TAGGED_PCR_SELECT, TPMS_TAGGED_PROPERTY, TPMS_TIME_ATTEST_INFO, TPMS_TIME_INFO,
+        TPMS_AC_OUTPUT, TPMS_ALGORITHM_DETAIL_ECC, TPMS_ALG_PROPERTY, TPMS_ATTEST,
+        TPMS_CERTIFY_INFO, TPMS_CLOCK_INFO, TPMS_COMMAND_AUDIT_INFO, TPMS_CONTEXT,
+        TPMS_CREATION_INFO, TPMS_ECC_PARMS, TPMS_EMPTY, TPMS_KEYEDHASH_PARMS, TPMS_NV_CERTIFY_INFO,
+        TPMS_PCR_SELECTION, TPMS_QUOTE_INFO, TPMS_RSA_PARMS, TPMS_SCHEME_ECDAA, TPMS_SCHEME_HASH,
+        TPMS_SCHEME_HMAC, TPMS_SCHEME_XOR, TPMS_SENSITIVE_CREATE, TPMS_SESSION_AUDIT_INFO,
+        TPMS_SYMCIPHER_PARMS, TPMS_TAGGED_PCR_SELECT, TPMS_TAGGED_PROPERTY, TPMS_TIME_ATTEST_INFO,
+        TPMS_TIME_INFO,
     },
 };
 
@@ -197,6 +200,36 @@ pub fn ensure_tpms_tagged_property_equality(
     );
 }
 
+pub fn ensure_tpms_ac_output_equality(expected: &TPMS_AC_OUTPUT, actual: &TPMS_AC_OUTPUT) {
+    assert_eq!(
+        expected.tag, actual.tag,
+        "'tag' value in TPMS_AC_OUTPUT, mismatch between actual and expected",
+    );
+
+    assert_eq!(
+        expected.data, actual.data,
+        "'data' value in TPMS_AC_OUTPUT, mismatch between actual and expected",
+    );
+}
+
+#[cfg(has_esys_act)]
+pub fn ensure_tpms_act_data_equality(expected: &TPMS_ACT_DATA, actual: &TPMS_ACT_DATA) {
+    assert_eq!(
+        expected.handle, actual.handle,
+        "'handle' value in TPMS_ACT_DATA, mismatch between actual and expected",
+    );
+
+    assert_eq!(
+        expected.timeout, actual.timeout,
+        "'timeout' value in TPMS_ACT_DATA, mismatch between actual and expected",
+    );
+
+    assert_eq!(
+        expected.attributes, actual.attributes,
+        "'attributes' value in TPMS_ACT_DATA, mismatch between actual and expected",
+    );
+}
+
 pub fn ensure_tpms_alg_property_equality(expected: &TPMS_ALG_PROPERTY, actual: &TPMS_ALG_PROPERTY) {
     assert_eq!(
         expected.alg, actual.alg,
@@ -332,3 +365,26 @@ pub fn ensure_tpms_context_equality(expected: &TPMS_CONTEXT, actual: &TPMS_CONTE
     );
     crate::common::ensure_tpm2b_context_data_equality(&expected.contextBlob, &actual.contextBlob);
 }
+
+pub fn ensure_tpms_algorithm_detail_ecc_equality(
+    expected: &TPMS_ALGORITHM_DETAIL_ECC,
+    actual: &TPMS_ALGORITHM_DETAIL_ECC,
+) {
+    assert_eq!(
+        expected.curveID, actual.curveID,
+        "'curveID' value in TPMS_ALGORITHM_DETAIL_ECC, mismatch between actual and expected",
+    );
+    assert_eq!(
+        expected.keySize, actual.keySize,
+        "'keySize' value in TPMS_ALGORITHM_DETAIL_ECC, mismatch between actual and expected",
+    );
+    crate::common::ensure_tpmt_kdf_scheme_equality(&expected.kdf, &actual.kdf);
+    crate::common::ensure_tpmt_ecc_scheme_equality(&expected.sign, &actual.sign);
+    crate::common::ensure_tpm2b_ecc_parameter_equality(&expected.p, &actual.p);
+    crate::common::ensure_tpm2b_ecc_parameter_equality(&expected.a, &actual.a);
+    crate::common::ensure_tpm2b_ecc_parameter_equality(&expected.b, &actual.b);
+    crate::common::ensure_tpm2b_ecc_parameter_equality(&expected.gX, &actual.gX);
+    crate::common::ensure_tpm2b_ecc_parameter_equality(&expected.gY, &actual.gY);
+    crate::common::ensure_tpm2b_ecc_parameter_equality(&expected.n, &actual.n);
+    crate::common::ensure_tpm2b_ecc_parameter_equality(&expected.h, &actual.h);
+}
@@ -0,0 +1,55 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{
+        tss::{TPM2_AT_ANY, TPM2_AT_ERROR, TPM2_AT_PV1, TPM2_AT_VEND},
+        AcCapability, VendorAcCapability,
+    },
+    tss2_esys::TPM_AT,
+};
+
+use std::convert::{From, TryFrom};
+
+const CONVERSIONS: [(TPM_AT, AcCapability); 3] = [
+    (TPM2_AT_ANY, AcCapability::Any),
+    (TPM2_AT_ERROR, AcCapability::Error),
+    (TPM2_AT_PV1, AcCapability::Pv1),
+];
+
+const VENDOR_VALUES: [TPM_AT; 3] = [TPM2_AT_VEND, TPM2_AT_VEND + 1, TPM_AT::MAX];
+
+#[test]
+fn test_conversion_to_tss_type() {
+    for (tss_value, ac_capability) in CONVERSIONS {
+        assert_eq!(tss_value, TPM_AT::from(ac_capability));
+    }
+}
+
+#[test]
+fn test_conversion_from_tss_type() {
+    for (tss_value, ac_capability) in CONVERSIONS {
+        assert_eq!(ac_capability, AcCapability::try_from(tss_value).unwrap());
+    }
+
+    const INVALID_VALUE: TPM_AT = 3;
+    let _ = AcCapability::try_from(INVALID_VALUE).unwrap_err();
+}
+
+#[test]
+fn test_vendor_conversions() {
+    for tss_value in VENDOR_VALUES {
+        let vendor_ac_capability = VendorAcCapability::try_from(tss_value)
+            .expect("Failed to convert vendor specific value");
+        assert_eq!(tss_value, vendor_ac_capability.value());
+        assert_eq!(tss_value, TPM_AT::from(vendor_ac_capability));
+
+        let ac_capability = AcCapability::try_from(tss_value)
+            .expect("Failed to convert vendor specific value to AcCapability");
+        assert_eq!(AcCapability::Vendor(vendor_ac_capability), ac_capability);
+        assert_eq!(tss_value, TPM_AT::from(ac_capability));
+    }
+
+    for tss_value in [TPM2_AT_ANY, TPM2_AT_ERROR, TPM2_AT_PV1, TPM2_AT_VEND - 1] {
+        let _ = VendorAcCapability::try_from(tss_value).unwrap_err();
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{
+        tss::{
+            TPM2_EO_BITCLEAR, TPM2_EO_BITSET, TPM2_EO_EQ, TPM2_EO_NEQ, TPM2_EO_SIGNED_GE,
+            TPM2_EO_SIGNED_GT, TPM2_EO_SIGNED_LE, TPM2_EO_SIGNED_LT, TPM2_EO_UNSIGNED_GE,
+            TPM2_EO_UNSIGNED_GT, TPM2_EO_UNSIGNED_LE, TPM2_EO_UNSIGNED_LT,
+        },
+        ArithmeticComparison,
+    },
+    tss2_esys::TPM2_EO,
+};
+
+use std::convert::{From, TryFrom};
+
+const CONVERSIONS: [(TPM2_EO, ArithmeticComparison); 12] = [
+    (TPM2_EO_EQ, ArithmeticComparison::Equal),
+    (TPM2_EO_NEQ, ArithmeticComparison::NotEqual),
+    (TPM2_EO_SIGNED_GT, ArithmeticComparison::SignedGreaterThan),
+    (
+        TPM2_EO_UNSIGNED_GT,
+        ArithmeticComparison::UnsignedGreaterThan,
+    ),
+    (TPM2_EO_SIGNED_LT, ArithmeticComparison::SignedLessThan),
+    (TPM2_EO_UNSIGNED_LT, ArithmeticComparison::UnsignedLessThan),
+    (
+        TPM2_EO_SIGNED_GE,
+        ArithmeticComparison::SignedGreaterOrEqual,
+    ),
+    (
+        TPM2_EO_UNSIGNED_GE,
+        ArithmeticComparison::UnsignedGreaterOrEqual,
+    ),
+    (TPM2_EO_SIGNED_LE, ArithmeticComparison::SignedLessOrEqual),
+    (
+        TPM2_EO_UNSIGNED_LE,
+        ArithmeticComparison::UnsignedLessOrEqual,
+    ),
+    (TPM2_EO_BITSET, ArithmeticComparison::BitSet),
+    (TPM2_EO_BITCLEAR, ArithmeticComparison::BitClear),
+];
+
+#[test]
+fn test_conversion_to_tss_type() {
+    for (tss_value, arithmetic_comparison) in CONVERSIONS {
+        assert_eq!(tss_value, TPM2_EO::from(arithmetic_comparison));
+    }
+}
+
+#[test]
+fn test_conversion_from_tss_type() {
+    for (tss_value, arithmetic_comparison) in CONVERSIONS {
+        assert_eq!(
+            arithmetic_comparison,
+            ArithmeticComparison::try_from(tss_value).unwrap()
+        );
+    }
+
+    const INVALID_VALUE: TPM2_EO = 0x000C;
+    let _ = ArithmeticComparison::try_from(INVALID_VALUE).unwrap_err();
+}
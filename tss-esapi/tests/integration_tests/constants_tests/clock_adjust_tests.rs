@@ -0,0 +1,42 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{
+        tss::{
+            TPM2_CLOCK_COARSE_FASTER, TPM2_CLOCK_COARSE_SLOWER, TPM2_CLOCK_FINE_FASTER,
+            TPM2_CLOCK_FINE_SLOWER, TPM2_CLOCK_MEDIUM_FASTER, TPM2_CLOCK_MEDIUM_SLOWER,
+            TPM2_CLOCK_NO_CHANGE,
+        },
+        ClockAdjust,
+    },
+    tss2_esys::TPM2_CLOCK_ADJUST,
+};
+
+use std::convert::{From, TryFrom};
+
+const CONVERSIONS: [(TPM2_CLOCK_ADJUST, ClockAdjust); 7] = [
+    (TPM2_CLOCK_COARSE_SLOWER, ClockAdjust::CoarseSlower),
+    (TPM2_CLOCK_MEDIUM_SLOWER, ClockAdjust::MediumSlower),
+    (TPM2_CLOCK_FINE_SLOWER, ClockAdjust::FineSlower),
+    (TPM2_CLOCK_NO_CHANGE, ClockAdjust::NoChange),
+    (TPM2_CLOCK_FINE_FASTER, ClockAdjust::FineFaster),
+    (TPM2_CLOCK_MEDIUM_FASTER, ClockAdjust::MediumFaster),
+    (TPM2_CLOCK_COARSE_FASTER, ClockAdjust::CoarseFaster),
+];
+
+#[test]
+fn test_conversion_to_tss_type() {
+    for (tss_value, clock_adjust) in CONVERSIONS {
+        assert_eq!(tss_value, TPM2_CLOCK_ADJUST::from(clock_adjust));
+    }
+}
+
+#[test]
+fn test_conversion_from_tss_type() {
+    for (tss_value, clock_adjust) in CONVERSIONS {
+        assert_eq!(clock_adjust, ClockAdjust::try_from(tss_value).unwrap());
+    }
+
+    const INVALID_VALUE: TPM2_CLOCK_ADJUST = 4;
+    let _ = ClockAdjust::try_from(INVALID_VALUE).unwrap_err();
+}
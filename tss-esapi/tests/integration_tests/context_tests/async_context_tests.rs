@@ -0,0 +1,307 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::common::create_ctx_with_session;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use tss_esapi::AsyncContext;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Drives the future to completion on the current thread.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut task_context = TaskContext::from_waker(&waker);
+    loop {
+        match Pin::as_mut(&mut future).poll(&mut task_context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+fn create_async_ctx_with_session() -> AsyncContext {
+    AsyncContext::from(create_ctx_with_session())
+}
+
+mod test_create_primary {
+    use super::*;
+    use crate::common::decryption_key_pub;
+    use tss_esapi::interface_types::reserved_handles::Hierarchy;
+
+    #[test]
+    fn test_create_primary() {
+        let mut context = create_async_ctx_with_session();
+        let key_handle = block_on(context.create_primary(
+            Hierarchy::Owner,
+            decryption_key_pub(),
+            None,
+            None,
+            None,
+            None,
+        ))
+        .expect("Call to create_primary failed")
+        .key_handle;
+
+        let (public, _, _) =
+            block_on(context.read_public(key_handle)).expect("Call to read_public failed");
+        assert_eq!(
+            decryption_key_pub().name_hashing_algorithm(),
+            public.name_hashing_algorithm()
+        );
+    }
+}
+
+mod test_create_and_load {
+    use super::*;
+    use crate::common::{decryption_key_pub, signing_key_pub};
+    use tss_esapi::interface_types::reserved_handles::Hierarchy;
+
+    #[test]
+    fn test_create_and_load() {
+        let mut context = create_async_ctx_with_session();
+        let primary_handle = block_on(context.create_primary(
+            Hierarchy::Owner,
+            decryption_key_pub(),
+            None,
+            None,
+            None,
+            None,
+        ))
+        .expect("Call to create_primary failed")
+        .key_handle;
+
+        let result =
+            block_on(context.create(primary_handle, signing_key_pub(), None, None, None, None))
+                .expect("Call to create failed");
+
+        let _ = block_on(context.load(primary_handle, result.out_private, result.out_public))
+            .expect("Call to load failed");
+    }
+}
+
+mod test_sign_and_verify_signature {
+    use super::*;
+    use crate::common::signing_key_pub;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::reserved_handles::Hierarchy,
+        structures::{Digest, SignatureScheme},
+    };
+
+    #[test]
+    fn test_sign_and_verify_signature() {
+        let mut context = create_async_ctx_with_session();
+        let key_handle = block_on(context.create_primary(
+            Hierarchy::Owner,
+            signing_key_pub(),
+            None,
+            None,
+            None,
+            None,
+        ))
+        .expect("Call to create_primary failed")
+        .key_handle;
+
+        let digest = Digest::try_from(vec![0xEE; 32]).expect("Failed to create digest");
+        let signature =
+            block_on(context.sign(key_handle, digest.clone(), SignatureScheme::Null, None))
+                .expect("Call to sign failed");
+
+        let _ = block_on(context.verify_signature(key_handle, digest, signature))
+            .expect("Call to verify_signature failed");
+    }
+}
+
+mod test_pcr_extend_and_read {
+    use super::*;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::PcrHandle,
+        interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+        structures::{Digest, DigestValues, PcrSelectionListBuilder, PcrSlot},
+    };
+
+    #[test]
+    fn test_pcr_extend_and_read() {
+        let mut context = create_async_ctx_with_session();
+        let pcr_selection_list = PcrSelectionListBuilder::new()
+            .with_selection(HashingAlgorithm::Sha256, &[PcrSlot::Slot16])
+            .build()
+            .expect("Failed to create PcrSelectionList");
+
+        let (update_counter_before, _, _) = block_on(context.pcr_read(pcr_selection_list.clone()))
+            .expect("Call to pcr_read failed");
+
+        let mut digests = DigestValues::new();
+        digests.set(
+            HashingAlgorithm::Sha256,
+            Digest::try_from(vec![0xAB; 32]).expect("Failed to create digest"),
+        );
+        let sessions = context.context().sessions();
+        context
+            .context_mut()
+            .set_sessions((Some(AuthSession::Password), None, None));
+        block_on(context.pcr_extend(PcrHandle::Pcr16, digests)).expect("Call to pcr_extend failed");
+        context.context_mut().set_sessions(sessions);
+
+        let (update_counter_after, pcr_selection_list_out, pcr_data) =
+            block_on(context.pcr_read(pcr_selection_list.clone()))
+                .expect("Call to pcr_read failed");
+        assert!(update_counter_after > update_counter_before);
+        assert_eq!(pcr_selection_list, pcr_selection_list_out);
+        assert_eq!(1, pcr_data.len());
+    }
+}
+
+mod test_nv_storage {
+    use super::*;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            reserved_handles::{NvAuth, Provision},
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder},
+    };
+
+    #[test]
+    fn test_nv_write_and_read() {
+        let mut context = create_async_ctx_with_session();
+
+        let nv_index = NvIndexTpmHandle::new(0x01500600).unwrap();
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+            .expect("Failed to create MaxNvBuffer from vec");
+
+        let nv_index_handle =
+            block_on(context.nv_define_space(Provision::Owner, None, owner_nv_public))
+                .expect("Call to nv_define_space failed");
+
+        let nv_write_result =
+            block_on(context.nv_write(NvAuth::Owner, nv_index_handle, data.clone(), 0));
+        let nv_read_result =
+            block_on(context.nv_read(NvAuth::Owner, nv_index_handle, data.len() as u16, 0));
+
+        block_on(context.nv_undefine_space(Provision::Owner, nv_index_handle))
+            .expect("Call to nv_undefine_space failed");
+
+        nv_write_result.expect("Call to nv_write failed");
+        assert_eq!(data, nv_read_result.expect("Call to nv_read failed"));
+    }
+}
+
+mod test_pending {
+    use super::*;
+    use std::time::Duration;
+    use tss_esapi::{structures::PcrSelectionList, tcti::Tcti, Context, Result};
+
+    /// TCTI that only answers the second attempt to receive the response.
+    struct DelayedTcti {
+        attempts: usize,
+    }
+
+    impl Tcti for DelayedTcti {
+        fn transmit(&mut self, _command: &[u8]) -> Result<()> {
+            self.attempts = 0;
+            Ok(())
+        }
+
+        fn receive(&mut self, _timeout: Option<Duration>) -> Result<Option<Vec<u8>>> {
+            self.attempts += 1;
+            if self.attempts == 1 {
+                return Ok(None);
+            }
+            // TPM2_ST_NO_SESSIONS, size, TPM_RC_SUCCESS, the update counter,
+            // an empty TPML_PCR_SELECTION and an empty TPML_DIGEST.
+            Ok(Some(vec![
+                0x80, 0x01, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2A,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            ]))
+        }
+    }
+
+    #[test]
+    fn test_pending_until_response() {
+        let mut context = AsyncContext::from(
+            Context::new_with_tcti(DelayedTcti { attempts: 0 }).expect("Failed to create context"),
+        );
+        let mut future = Box::pin(context.pcr_read(PcrSelectionList::default()));
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut task_context = TaskContext::from_waker(&waker);
+
+        assert!(Pin::as_mut(&mut future)
+            .poll(&mut task_context)
+            .is_pending());
+        let (update_counter, pcr_selection_list, pcr_data) = loop {
+            // The waiter wakes the task after the poll timeout
+            // as the TCTI does not provide poll handles.
+            thread::park();
+            if let Poll::Ready(output) = Pin::as_mut(&mut future).poll(&mut task_context) {
+                break output.expect("Call to pcr_read failed");
+            }
+        };
+        assert_eq!(42, update_counter);
+        assert!(pcr_selection_list.is_empty());
+        assert!(pcr_data.is_empty());
+    }
+
+    #[test]
+    fn test_abandoned_command() {
+        let mut context = AsyncContext::from(
+            Context::new_with_tcti(DelayedTcti { attempts: 0 }).expect("Failed to create context"),
+        );
+        {
+            let mut future = Box::pin(context.pcr_read(PcrSelectionList::default()));
+            let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+            let mut task_context = TaskContext::from_waker(&waker);
+            assert!(Pin::as_mut(&mut future)
+                .poll(&mut task_context)
+                .is_pending());
+        }
+        // The response of the dropped command is received
+        // before the next command is sent.
+        let (update_counter, _, _) = block_on(context.pcr_read(PcrSelectionList::default()))
+            .expect("Call to pcr_read failed");
+        assert_eq!(42, update_counter);
+    }
+
+    #[test]
+    fn test_future_on_other_thread() {
+        let mut context = AsyncContext::from(
+            Context::new_with_tcti(DelayedTcti { attempts: 0 }).expect("Failed to create context"),
+        );
+        let future = context.pcr_read(PcrSelectionList::default());
+        let (update_counter, _, _) = thread::scope(|scope| {
+            scope
+                .spawn(move || block_on(future))
+                .join()
+                .expect("Thread executing pcr_read panicked")
+        })
+        .expect("Call to pcr_read failed");
+        assert_eq!(42, update_counter);
+    }
+}
@@ -1,2 +1,5 @@
+#[cfg(feature = "async")]
+mod async_context_tests;
 mod general_esys_tr_tests;
+mod session_administration_tests;
 mod tpm_commands;
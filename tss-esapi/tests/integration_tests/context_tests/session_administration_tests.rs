@@ -0,0 +1,64 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::common::create_ctx_without_session;
+use tss_esapi::{
+    constants::SessionType,
+    interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+    structures::SymmetricDefinition,
+    Context,
+};
+
+fn start_policy_session(context: &mut Context) -> AuthSession {
+    context
+        .start_auth_session(
+            None,
+            None,
+            None,
+            SessionType::Policy,
+            SymmetricDefinition::AES_256_CFB,
+            HashingAlgorithm::Sha256,
+        )
+        .expect("Start auth session failed")
+        .expect("Start auth session returned a NONE handle")
+}
+
+mod test_tr_sess_get_nonce_tpm {
+    use super::*;
+
+    #[test]
+    fn test_tr_sess_get_nonce_tpm() {
+        let mut context = create_ctx_without_session();
+        let session = start_policy_session(&mut context);
+
+        let nonce_tpm = context
+            .tr_sess_get_nonce_tpm(session)
+            .expect("Failed to get nonceTPM");
+        // The TPM produces a nonce of the size of the session hash.
+        assert_eq!(32, nonce_tpm.len());
+    }
+}
+
+#[cfg(has_esys_trsess_get_auth_required)]
+mod test_tr_sess_get_auth_required {
+    use super::*;
+    use std::convert::TryFrom;
+    use tss_esapi::interface_types::session_handles::PolicySession;
+
+    #[test]
+    fn test_tr_sess_get_auth_required() {
+        let mut context = create_ctx_without_session();
+        let session = start_policy_session(&mut context);
+
+        assert!(!context
+            .tr_sess_get_auth_required(session)
+            .expect("Failed to get auth required"));
+
+        context
+            .policy_password(PolicySession::try_from(session).expect("Invalid policy session"))
+            .expect("Failed to call policy_password");
+
+        assert!(context
+            .tr_sess_get_auth_required(session)
+            .expect("Failed to get auth required"));
+    }
+}
@@ -106,3 +106,124 @@ mod test_rsa_encrypt_decrypt {
         assert_eq!(z_point.x().as_bytes(), param.x().as_bytes());
     }
 }
+
+mod test_zgen_2phase {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        handles::KeyHandle,
+        interface_types::{
+            algorithm::{EccKeyExchangeAlgorithm, HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            reserved_handles::Hierarchy,
+        },
+        structures::{
+            EccPoint, EccScheme, KeyDerivationFunctionScheme, Public, PublicBuilder,
+            PublicEccParametersBuilder,
+        },
+        Context,
+    };
+
+    fn create_exchange_key(context: &mut Context) -> (KeyHandle, EccPoint) {
+        let ecc_parms = PublicEccParametersBuilder::new()
+            .with_ecc_scheme(EccScheme::Null)
+            .with_curve(EccCurve::NistP256)
+            .with_is_signing_key(false)
+            .with_is_decryption_key(true)
+            .with_restricted(false)
+            .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+            .build()
+            .unwrap();
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_sign_encrypt(false)
+            .with_restricted(false)
+            .build()
+            .unwrap();
+
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(ecc_parms)
+            .with_ecc_unique_identifier(EccPoint::default())
+            .build()
+            .unwrap();
+
+        let result = context
+            .create_primary(Hierarchy::Owner, public, None, None, None, None)
+            .unwrap();
+        match result.out_public {
+            Public::Ecc { unique, .. } => (result.key_handle, unique),
+            _ => panic!("Created key is not an ECC key"),
+        }
+    }
+
+    #[test]
+    fn test_zgen_2phase_ecdh() {
+        let mut context = create_ctx_with_session();
+        let (key_a, static_a) = create_exchange_key(&mut context);
+        let (key_b, static_b) = create_exchange_key(&mut context);
+
+        let (ephemeral_a, counter_a) = context.ec_ephemeral(EccCurve::NistP256).unwrap();
+        let (ephemeral_b, counter_b) = context.ec_ephemeral(EccCurve::NistP256).unwrap();
+
+        let (z1_a, z2_a) = context
+            .zgen_2phase(
+                key_a,
+                static_b,
+                ephemeral_b,
+                EccKeyExchangeAlgorithm::EcDh,
+                counter_a,
+            )
+            .expect("Call to zgen_2phase failed for party A");
+        let (z1_b, z2_b) = context
+            .zgen_2phase(
+                key_b,
+                static_a,
+                ephemeral_a,
+                EccKeyExchangeAlgorithm::EcDh,
+                counter_b,
+            )
+            .expect("Call to zgen_2phase failed for party B");
+
+        assert_eq!(z1_a.x().as_bytes(), z1_b.x().as_bytes());
+        assert_eq!(z2_a.x().as_bytes(), z2_b.x().as_bytes());
+    }
+}
+
+mod test_ecc_parameters {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::interface_types::ecc::EccCurve;
+
+    #[test]
+    fn test_ecc_parameters_nist_p256() {
+        let mut context = create_ctx_without_session();
+        let parameters = context
+            .ecc_parameters(EccCurve::NistP256)
+            .expect("Call to ecc_parameters failed");
+
+        assert_eq!(EccCurve::NistP256, parameters.curve());
+        assert_eq!(256, parameters.key_size());
+        // p = 2^256 - 2^224 + 2^192 + 2^96 - 1
+        let expected_p: [u8; 32] = [
+            0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff,
+        ];
+        assert_eq!(&expected_p[..], parameters.p().as_bytes());
+        // a = p - 3
+        let mut expected_a = expected_p;
+        expected_a[31] = 0xfc;
+        assert_eq!(&expected_a[..], parameters.a().as_bytes());
+        assert_eq!(32, parameters.g_x().len());
+        assert_eq!(32, parameters.g_y().len());
+        assert_eq!(32, parameters.n().len());
+        assert_eq!(Some(&1u8), parameters.h().as_bytes().last());
+    }
+}
@@ -1,2 +1,124 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_ac_commands)]
+mod test_ac_get_capability {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::{tss::TPM2_AC_FIRST, AcCapability, CapabilityType},
+        handles::{AttachedComponentTpmHandle, TpmHandle},
+        structures::CapabilityData,
+    };
+
+    #[test]
+    fn test_ac_get_capability() {
+        let mut context = create_ctx_without_session();
+        let (capability_data, _) = context
+            .get_capability(CapabilityType::Handles, TPM2_AC_FIRST, 8)
+            .expect("Failed to list the attached component handles");
+        let handles = if let CapabilityData::Handles(handles) = capability_data {
+            handles
+        } else {
+            panic!("Got wrong type of capability data: {:?}", capability_data);
+        };
+
+        if handles.is_empty() {
+            // Without attached components the command cannot succeed.
+            let ac_tpm_handle = AttachedComponentTpmHandle::new(TPM2_AC_FIRST)
+                .expect("Failed to create attached component TPM handle");
+            let _ = context
+                .tr_from_tpm_public(TpmHandle::AttachedComponent(ac_tpm_handle))
+                .and_then(|ac| context.ac_get_capability(ac, AcCapability::Any, 8))
+                .unwrap_err();
+            return;
+        }
+
+        // The attached components are platform specific, so only the
+        // ones that the TPM reports are queried.
+        for tpm_handle in handles.into_inner() {
+            let mut ac = context
+                .tr_from_tpm_public(tpm_handle)
+                .expect("Failed to get the attached component handle");
+            let (ac_capabilities, _more_data) = context
+                .ac_get_capability(ac, AcCapability::Any, 8)
+                .expect("Call to ac_get_capability failed");
+            assert!(
+                !ac_capabilities.is_empty(),
+                "The attached component did not report any capabilities"
+            );
+            context
+                .tr_close(&mut ac)
+                .expect("Failed to close the attached component handle");
+        }
+    }
+}
+
+#[cfg(has_esys_ac_commands)]
+mod test_policy_ac_send_select {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::SessionAttributesBuilder,
+        constants::{
+            tss::{TPM2_AC_FIRST, TPM2_RH_OWNER},
+            SessionType,
+        },
+        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
+        structures::{Name, SymmetricDefinition},
+    };
+
+    #[test]
+    fn test_policy_ac_send_select() {
+        let mut context = create_ctx_without_session();
+        let trial_policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let (trial_policy_auth_session_attributes, trial_policy_auth_session_attributes_mask) =
+            SessionAttributesBuilder::new()
+                .with_decrypt(true)
+                .with_encrypt(true)
+                .build();
+        context
+            .tr_sess_set_attributes(
+                trial_policy_auth_session,
+                trial_policy_auth_session_attributes,
+                trial_policy_auth_session_attributes_mask,
+            )
+            .expect("tr_sess_set_attributes call failed");
+        let trial_policy_session = PolicySession::try_from(trial_policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+
+        let initial_digest = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to get the initial policy digest");
+
+        // The names of permanent handles and attached components are their handle values.
+        let object_name = Name::try_from(vec![0x00; 34]).expect("Failed to create object name");
+        let auth_handle_name = Name::try_from(TPM2_RH_OWNER.to_be_bytes().to_vec())
+            .expect("Failed to create auth handle name");
+        let ac_name = Name::try_from(TPM2_AC_FIRST.to_be_bytes().to_vec())
+            .expect("Failed to create attached component name");
+
+        context
+            .policy_ac_send_select(
+                trial_policy_session,
+                object_name,
+                auth_handle_name,
+                ac_name,
+                false,
+            )
+            .expect("Call to policy_ac_send_select failed");
+
+        let policy_digest = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to get the policy digest");
+        assert_ne!(initial_digest, policy_digest);
+    }
+}
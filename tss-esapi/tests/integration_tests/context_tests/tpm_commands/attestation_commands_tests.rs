@@ -221,3 +221,342 @@ mod test_quote {
         assert!(matches!(attest.attested(), AttestInfo::Creation { .. }));
     }
 }
+
+mod test_get_session_audit_digest {
+    use crate::common::{create_ctx_without_session, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::SessionAttributesBuilder,
+        constants::SessionType,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            reserved_handles::{Endorsement, Hierarchy},
+            session_handles::AuthSession,
+            structure_tags::AttestationType,
+        },
+        structures::{AttestInfo, Data, SignatureScheme, SymmetricDefinition},
+        Error, WrapperErrorKind,
+    };
+
+    #[test]
+    fn get_session_audit_digest() {
+        let mut context = create_ctx_without_session();
+        let qualifying_data = vec![0xff; 16];
+
+        let sign_key_handle = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            })
+            .unwrap()
+            .key_handle;
+
+        let audit_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Hmac,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let (session_attributes, session_attributes_mask) = SessionAttributesBuilder::new()
+            .with_continue_session(true)
+            .with_audit(true)
+            .build();
+        context
+            .tr_sess_set_attributes(audit_session, session_attributes, session_attributes_mask)
+            .expect("tr_sess_set_attributes call failed");
+
+        // Run a command in the audit session.
+        let _ = context
+            .execute_with_session(Some(audit_session), |ctx| ctx.get_random(16))
+            .expect("Call to get_random failed");
+
+        let (attest, _signature) = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.get_session_audit_digest(
+                        Endorsement::Endorsement,
+                        sign_key_handle,
+                        audit_session,
+                        Data::try_from(qualifying_data.clone()).unwrap(),
+                        SignatureScheme::Null,
+                    )
+                },
+            )
+            .expect("Failed to get session audit digest");
+
+        assert_eq!(attest.attestation_type(), AttestationType::SessionAudit);
+        assert_eq!(attest.extra_data().as_bytes(), qualifying_data);
+        match attest.attested() {
+            AttestInfo::SessionAudit { info } => {
+                assert_eq!(info.session_digest().len(), 32);
+            }
+            _ => {
+                panic!("Attested did not contain the expected variant.")
+            }
+        }
+    }
+
+    #[test]
+    fn get_session_audit_digest_password_session() {
+        let mut context = create_ctx_without_session();
+
+        let sign_key_handle = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            })
+            .unwrap()
+            .key_handle;
+
+        let result = context.get_session_audit_digest(
+            Endorsement::Endorsement,
+            sign_key_handle,
+            AuthSession::Password,
+            Data::default(),
+            SignatureScheme::Null,
+        );
+        assert_eq!(
+            Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+            result.map(|_| ())
+        );
+    }
+}
+
+mod test_get_command_audit_digest {
+    use crate::common::{create_ctx_without_session, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::CommandCode,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            reserved_handles::{Endorsement, Hierarchy, Provision},
+            session_handles::AuthSession,
+            structure_tags::AttestationType,
+        },
+        structures::{AttestInfo, CommandCodeList, Data, SignatureScheme},
+    };
+
+    #[test]
+    fn get_command_audit_digest() {
+        let mut context = create_ctx_without_session();
+        let qualifying_data = vec![0xff; 16];
+
+        let sign_key_handle = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            })
+            .unwrap()
+            .key_handle;
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                // Select the hashing algorithm used for the audit digest.
+                ctx.set_command_code_audit_status(
+                    Provision::Owner,
+                    HashingAlgorithm::Sha256,
+                    CommandCodeList::new(),
+                    CommandCodeList::new(),
+                )?;
+                // Start auditing the GetRandom command.
+                ctx.set_command_code_audit_status(
+                    Provision::Owner,
+                    HashingAlgorithm::Null,
+                    CommandCodeList::try_from(vec![CommandCode::GetRandom])?,
+                    CommandCodeList::new(),
+                )
+            })
+            .expect("Call to set_command_code_audit_status failed");
+        let _ = context.get_random(16).expect("Call to get_random failed");
+
+        let (attest, _signature) = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.get_command_audit_digest(
+                        Endorsement::Endorsement,
+                        sign_key_handle,
+                        Data::try_from(qualifying_data.clone()).unwrap(),
+                        SignatureScheme::Null,
+                    )
+                },
+            )
+            .expect("Failed to get command audit digest");
+
+        assert_eq!(attest.attestation_type(), AttestationType::CommandAudit);
+        assert_eq!(attest.extra_data().as_bytes(), qualifying_data);
+        match attest.attested() {
+            AttestInfo::CommandAudit { info } => {
+                assert!(info.audit_counter() > 0);
+                assert!(!info.command_digest().is_empty());
+            }
+            _ => {
+                panic!("Attested did not contain the expected variant.")
+            }
+        }
+
+        // Stop auditing the GetRandom command.
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.set_command_code_audit_status(
+                    Provision::Owner,
+                    HashingAlgorithm::Null,
+                    CommandCodeList::new(),
+                    CommandCodeList::try_from(vec![CommandCode::GetRandom])?,
+                )
+            })
+            .expect("Call to set_command_code_audit_status failed");
+    }
+}
+
+mod test_get_time {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::StructureTag,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            reserved_handles::{Endorsement, Hierarchy},
+            session_handles::AuthSession,
+            structure_tags::AttestationType,
+        },
+        structures::{AttestInfo, Data, MaxBuffer, SignatureScheme, Ticket},
+        traits::Marshall,
+    };
+
+    #[test]
+    fn get_time() {
+        let mut context = create_ctx_with_session();
+        let qualifying_data = vec![0xff; 16];
+
+        let sign_key_handle = context
+            .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        let (attest, signature) = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.get_time(
+                        Endorsement::Endorsement,
+                        sign_key_handle,
+                        Data::try_from(qualifying_data.clone()).unwrap(),
+                        SignatureScheme::Null,
+                    )
+                },
+            )
+            .expect("Failed to get time");
+
+        // Verify the signature is valid for the attestation data
+        let data = MaxBuffer::try_from(attest.marshall().unwrap())
+            .expect("Failed to get data buffer from attestation data");
+        let (digest, _) = context
+            .hash(data, HashingAlgorithm::Sha256, Hierarchy::Null)
+            .expect("Failed to hash data");
+
+        let ticket = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.verify_signature(sign_key_handle, digest, signature)
+            })
+            .expect("Failed to verify signature");
+        assert_eq!(ticket.tag(), StructureTag::Verified);
+
+        // Verify the attestation data is as expected
+        assert_eq!(attest.attestation_type(), AttestationType::Time);
+        assert_eq!(attest.extra_data().as_bytes(), qualifying_data);
+        match attest.attested() {
+            AttestInfo::Time { info } => {
+                assert_eq!(info.firmware_version(), attest.firmware_version());
+            }
+            _ => {
+                panic!("Attested did not contain the expected variant.")
+            }
+        }
+    }
+}
+
+#[cfg(has_esys_certify_x509)]
+mod test_certify_x509 {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::{
+            algorithm::SignatureSchemeAlgorithm, reserved_handles::Hierarchy,
+            session_handles::AuthSession,
+        },
+        structures::{MaxBuffer, SignatureScheme},
+    };
+
+    // Name: CN=tss-esapi
+    const NAME: [u8; 22] = [
+        0x30, 0x14, 0x31, 0x12, 0x30, 0x10, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x09, 0x74, 0x73,
+        0x73, 0x2d, 0x65, 0x73, 0x61, 0x70, 0x69,
+    ];
+    // Validity: 2024-01-01 00:00:00 UTC to 2034-01-01 00:00:00 UTC
+    const VALIDITY: [u8; 32] = [
+        0x30, 0x1e, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30,
+        0x30, 0x5a, 0x17, 0x0d, 0x33, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30,
+        0x30, 0x5a,
+    ];
+    // [3] Extensions: critical key usage with digitalSignature
+    const EXTENSIONS: [u8; 20] = [
+        0xa3, 0x12, 0x30, 0x10, 0x30, 0x0e, 0x06, 0x03, 0x55, 0x1d, 0x0f, 0x01, 0x01, 0xff, 0x04,
+        0x04, 0x03, 0x02, 0x07, 0x80,
+    ];
+
+    #[test]
+    fn certify_x509() {
+        let mut context = create_ctx_with_session();
+
+        let sign_key_handle = context
+            .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+        let obj_key_handle = context
+            .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        let partial_certificate =
+            [&[0x30, 0x60][..], &NAME, &VALIDITY, &NAME, &EXTENSIONS].concat();
+
+        let (added_to_certificate, tbs_digest, signature) = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.certify_x509(
+                        obj_key_handle.into(),
+                        sign_key_handle,
+                        SignatureScheme::Null,
+                        MaxBuffer::try_from(partial_certificate).unwrap(),
+                    )
+                },
+            )
+            .expect("Failed to certify X.509");
+
+        // The added fields are returned as a DER encoded SEQUENCE.
+        assert_eq!(added_to_certificate.as_bytes()[0], 0x30);
+        assert_eq!(tbs_digest.len(), 32);
+        assert_eq!(signature.algorithm(), SignatureSchemeAlgorithm::RsaSsa);
+    }
+}
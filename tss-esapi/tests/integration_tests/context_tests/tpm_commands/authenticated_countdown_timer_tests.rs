@@ -1,2 +1,55 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_act)]
+mod test_act_set_timeout {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::{tss::TPM2_RH_ACT_0, CapabilityType},
+        interface_types::{reserved_handles::Act, session_handles::AuthSession},
+        structures::CapabilityData,
+    };
+
+    #[test]
+    fn test_act_set_timeout() {
+        let mut context = create_ctx_without_session();
+        let (capability_data, _) = context
+            .get_capability(CapabilityType::Act, TPM2_RH_ACT_0, 16)
+            .expect("Failed to get the ACT capabilities");
+        let act_data_list = if let CapabilityData::ActData(act_data_list) = capability_data {
+            act_data_list
+        } else {
+            panic!("Got wrong type of capability data: {:?}", capability_data);
+        };
+
+        // Authenticated countdown timers are optional, so the command
+        // is expected to fail if the TPM does not implement the ACT.
+        let act = act_data_list
+            .first()
+            .map(|act_data| act_data.act())
+            .unwrap_or(Act::Act0);
+        let result = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.act_set_timeout(act, 0)
+        });
+        if act_data_list.is_empty() {
+            let _ = result.unwrap_err();
+            return;
+        }
+        result.expect("Call to act_set_timeout failed");
+
+        // A timeout of zero does not start the countdown.
+        let (capability_data, _) = context
+            .get_capability(CapabilityType::Act, TPM2_RH_ACT_0, 16)
+            .expect("Failed to get the ACT capabilities");
+        if let CapabilityData::ActData(act_data_list) = capability_data {
+            assert_eq!(
+                0,
+                act_data_list
+                    .find(act)
+                    .expect("The ACT was not found")
+                    .timeout()
+            );
+        } else {
+            panic!("Got wrong type of capability data: {:?}", capability_data);
+        }
+    }
+}
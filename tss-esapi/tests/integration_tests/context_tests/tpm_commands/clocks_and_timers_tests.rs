@@ -1,2 +1,74 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_read_clock {
+    use crate::common::create_ctx_without_session;
+
+    #[test]
+    fn test_read_clock() {
+        let mut context = create_ctx_without_session();
+        let first = context.read_clock().expect("Call to read_clock failed");
+        let second = context.read_clock().expect("Call to read_clock failed");
+        assert!(second.clock_info().clock() >= first.clock_info().clock());
+        assert_eq!(
+            first.clock_info().reset_count(),
+            second.clock_info().reset_count()
+        );
+        assert_eq!(
+            first.clock_info().restart_count(),
+            second.clock_info().restart_count()
+        );
+    }
+}
+
+mod test_clock_set {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::interface_types::reserved_handles::Provision;
+
+    #[test]
+    fn test_clock_set() {
+        let mut context = create_ctx_with_session();
+        let clock = context
+            .read_clock()
+            .expect("Call to read_clock failed")
+            .clock_info()
+            .clock();
+        let new_time = clock + 60 * 60 * 1000;
+        context
+            .clock_set(Provision::Owner, new_time)
+            .expect("Call to clock_set failed");
+        let updated_clock = context
+            .read_clock()
+            .expect("Call to read_clock failed")
+            .clock_info()
+            .clock();
+        assert!(updated_clock >= new_time);
+    }
+
+    #[test]
+    fn test_clock_set_backwards() {
+        let mut context = create_ctx_with_session();
+        // The clock can not be set to an earlier value.
+        let _ = context
+            .clock_set(Provision::Owner, 0)
+            .expect_err("Setting the clock backwards succeeded");
+    }
+}
+
+mod test_clock_rate_adjust {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{constants::ClockAdjust, interface_types::reserved_handles::Provision};
+
+    #[test]
+    fn test_clock_rate_adjust() {
+        let mut context = create_ctx_with_session();
+        context
+            .clock_rate_adjust(Provision::Owner, ClockAdjust::FineFaster)
+            .expect("Call to clock_rate_adjust failed");
+        context
+            .clock_rate_adjust(Provision::Owner, ClockAdjust::FineSlower)
+            .expect("Call to clock_rate_adjust failed");
+        context
+            .clock_rate_adjust(Provision::Platform, ClockAdjust::NoChange)
+            .expect("Call to clock_rate_adjust failed");
+    }
+}
@@ -1,2 +1,58 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_set_command_code_audit_status {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{CapabilityType, CommandCode},
+        interface_types::{algorithm::HashingAlgorithm, reserved_handles::Provision},
+        structures::{CapabilityData, CommandCodeList},
+        Context,
+    };
+
+    fn audited_commands(context: &mut Context) -> Vec<CommandCode> {
+        let (capability_data, _) = context
+            .get_capability(CapabilityType::AuditCommands, 0, 80)
+            .expect("Call to get_capability failed");
+        if let CapabilityData::AuditCommands(list) = capability_data {
+            list.into_inner()
+        } else {
+            panic!("Got wrong type of capability data: {:?}", capability_data);
+        }
+    }
+
+    #[test]
+    fn test_set_and_clear_audited_command() {
+        let mut context = create_ctx_with_session();
+        context
+            .set_command_code_audit_status(
+                Provision::Owner,
+                HashingAlgorithm::Sha256,
+                CommandCodeList::new(),
+                CommandCodeList::new(),
+            )
+            .expect("Call to set_command_code_audit_status failed");
+
+        let command_codes = CommandCodeList::try_from(vec![CommandCode::TestParms])
+            .expect("Failed to create CommandCodeList");
+        context
+            .set_command_code_audit_status(
+                Provision::Owner,
+                HashingAlgorithm::Null,
+                command_codes.clone(),
+                CommandCodeList::new(),
+            )
+            .expect("Call to set_command_code_audit_status failed");
+        assert!(audited_commands(&mut context).contains(&CommandCode::TestParms));
+
+        context
+            .set_command_code_audit_status(
+                Provision::Owner,
+                HashingAlgorithm::Null,
+                CommandCodeList::new(),
+                command_codes,
+            )
+            .expect("Call to set_command_code_audit_status failed");
+        assert!(!audited_commands(&mut context).contains(&CommandCode::TestParms));
+    }
+}
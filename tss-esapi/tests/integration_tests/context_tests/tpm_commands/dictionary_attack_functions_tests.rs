@@ -1,2 +1,97 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_dictionary_attack_lock_reset {
+    use crate::common::{create_ctx_without_session, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        abstraction::dictionary_attack,
+        interface_types::{
+            reserved_handles::{Hierarchy, Lockout},
+            session_handles::AuthSession,
+        },
+        structures::{Auth, Digest, SignatureScheme},
+    };
+
+    #[test]
+    fn test_dictionary_attack_lock_reset() {
+        let mut context = create_ctx_without_session();
+        context.set_sessions((Some(AuthSession::Password), None, None));
+        context
+            .dictionary_attack_lock_reset(Lockout::Lockout)
+            .expect("Failed to reset the lockout");
+
+        let key_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                signing_key_pub(),
+                Some(Auth::try_from(vec![1, 2, 3, 4]).unwrap()),
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create signing key")
+            .key_handle;
+        // Authorize the key with the wrong auth value in order to
+        // register an authorization failure.
+        context
+            .tr_set_auth(key_handle.into(), Auth::try_from(vec![4, 3, 2, 1]).unwrap())
+            .unwrap();
+        let _ = context
+            .sign(
+                key_handle,
+                Digest::try_from(vec![0xff; 32]).unwrap(),
+                SignatureScheme::Null,
+                None,
+            )
+            .expect_err("Signed using the wrong auth value");
+        assert_eq!(
+            1,
+            dictionary_attack::get_status(&mut context)
+                .unwrap()
+                .lockout_counter()
+        );
+
+        context
+            .dictionary_attack_lock_reset(Lockout::Lockout)
+            .expect("Failed to reset the lockout");
+        assert_eq!(
+            0,
+            dictionary_attack::get_status(&mut context)
+                .unwrap()
+                .lockout_counter()
+        );
+        context.flush_context(key_handle.into()).unwrap();
+    }
+}
+
+mod test_dictionary_attack_parameters {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        abstraction::dictionary_attack,
+        interface_types::{reserved_handles::Lockout, session_handles::AuthSession},
+    };
+
+    #[test]
+    fn test_dictionary_attack_parameters() {
+        let mut context = create_ctx_without_session();
+        context.set_sessions((Some(AuthSession::Password), None, None));
+        let original = dictionary_attack::get_status(&mut context).unwrap();
+
+        context
+            .dictionary_attack_parameters(Lockout::Lockout, 7, 1234, 4321)
+            .expect("Failed to set the dictionary attack parameters");
+        let status = dictionary_attack::get_status(&mut context).unwrap();
+        assert_eq!(7, status.max_auth_fail());
+        assert_eq!(1234, status.lockout_interval());
+        assert_eq!(4321, status.lockout_recovery());
+
+        context
+            .dictionary_attack_parameters(
+                Lockout::Lockout,
+                original.max_auth_fail(),
+                original.lockout_interval(),
+                original.lockout_recovery(),
+            )
+            .expect("Failed to restore the dictionary attack parameters");
+    }
+}
@@ -303,3 +303,264 @@ mod test_duplicate {
         eprintln!("P: {:?}", private);
     }
 }
+
+mod test_rewrap {
+    use crate::common::{create_ctx_with_session, create_ctx_without_session};
+    use std::convert::TryFrom;
+    use tss_esapi::attributes::{ObjectAttributesBuilder, SessionAttributesBuilder};
+    use tss_esapi::constants::{CommandCode, SessionType};
+    use tss_esapi::handles::ObjectHandle;
+    use tss_esapi::interface_types::{
+        algorithm::{HashingAlgorithm, PublicAlgorithm},
+        ecc::EccCurve,
+        reserved_handles::Hierarchy,
+        session_handles::PolicySession,
+    };
+    use tss_esapi::structures::SymmetricDefinition;
+    use tss_esapi::structures::{
+        EccPoint, EccScheme, KeyDerivationFunctionScheme, PublicBuilder,
+        PublicEccParametersBuilder, SymmetricDefinitionObject,
+    };
+
+    #[test]
+    fn test_rewrap() {
+        // Trial session will be used to compute a policy digest.
+        // The policy will allow key duplication.
+        let mut context = create_ctx_without_session();
+
+        let trial_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+
+        let policy_session = PolicySession::try_from(trial_session)
+            .expect("Failed to convert auth session into policy session");
+
+        context
+            .policy_command_code(policy_session, CommandCode::Duplicate)
+            .expect("Policy command code");
+
+        let digest = context
+            .policy_get_digest(policy_session)
+            .expect("Could retrieve digest");
+
+        drop(context);
+        let mut context = create_ctx_with_session();
+
+        let parent_object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_sign_encrypt(false)
+            .with_restricted(true)
+            .build()
+            .expect("Attributes to be valid");
+
+        let public_parent = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(parent_object_attributes)
+            .with_ecc_parameters(
+                PublicEccParametersBuilder::new()
+                    .with_ecc_scheme(EccScheme::Null)
+                    .with_curve(EccCurve::NistP256)
+                    .with_is_signing_key(false)
+                    .with_is_decryption_key(true)
+                    .with_restricted(true)
+                    .with_symmetric(SymmetricDefinitionObject::AES_128_CFB)
+                    .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+                    .build()
+                    .expect("Params to be valid"),
+            )
+            .with_ecc_unique_identifier(EccPoint::default())
+            .build()
+            .expect("public to be valid");
+
+        // Fixed TPM and Fixed Parent should be "false" for an object
+        // to be eligible for duplication
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(false)
+            .with_fixed_parent(false)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_sign_encrypt(true)
+            .with_restricted(false)
+            .build()
+            .expect("Attributes to be valid");
+
+        let public_child = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            // Use policy digest computed using the trial session
+            .with_auth_policy(digest)
+            .with_ecc_parameters(
+                PublicEccParametersBuilder::new()
+                    .with_ecc_scheme(EccScheme::Null)
+                    .with_curve(EccCurve::NistP256)
+                    .with_is_signing_key(false)
+                    .with_is_decryption_key(true)
+                    .with_restricted(false)
+                    .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+                    .build()
+                    .expect("Params to be valid"),
+            )
+            .with_ecc_unique_identifier(EccPoint::default())
+            .build()
+            .expect("public to be valid");
+
+        let parent_of_object_to_duplicate_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                public_parent.clone(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+
+        let result = context
+            .create(
+                parent_of_object_to_duplicate_handle,
+                public_child,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let object_to_duplicate_handle: ObjectHandle = context
+            .load(
+                parent_of_object_to_duplicate_handle,
+                result.out_private,
+                result.out_public,
+            )
+            .unwrap()
+            .into();
+
+        let (public, object_name, _) = context
+            .read_public(object_to_duplicate_handle.into())
+            .unwrap();
+
+        // Unload the parent that is no longer needed to make room for the other parents.
+        context
+            .flush_context(parent_of_object_to_duplicate_handle.into())
+            .expect("Failed to flush parent of object to duplicate");
+
+        // The parent the object is duplicated to, which is the old parent
+        // of the duplicated object when it is rewrapped.
+        let old_parent_handle: ObjectHandle = context
+            .create_primary(
+                Hierarchy::Null,
+                public_parent.clone(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle
+            .into();
+
+        let session = context.sessions().0;
+        context.set_sessions((None, None, None));
+
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Policy,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let (policy_auth_session_attributes, policy_auth_session_attributes_mask) =
+            SessionAttributesBuilder::new()
+                .with_decrypt(true)
+                .with_encrypt(true)
+                .build();
+        context
+            .tr_sess_set_attributes(
+                policy_auth_session,
+                policy_auth_session_attributes,
+                policy_auth_session_attributes_mask,
+            )
+            .expect("tr_sess_set_attributes call failed");
+
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+
+        context
+            .policy_command_code(policy_session, CommandCode::Duplicate)
+            .unwrap();
+        context.set_sessions((Some(policy_auth_session), None, None));
+
+        // Duplicate the object to the old parent.
+        let (data, duplicate, secret) = context
+            .duplicate(
+                object_to_duplicate_handle,
+                old_parent_handle,
+                None,
+                SymmetricDefinitionObject::Null,
+            )
+            .unwrap();
+
+        context.set_sessions((session, None, None));
+        context
+            .flush_context(object_to_duplicate_handle)
+            .expect("Failed to flush object to duplicate");
+
+        // Rewrap the duplicated object so that it is protected by a new parent.
+        // The parent is created in a different hierarchy
+        // so that it is not the same key as the old parent.
+        let new_parent_handle: ObjectHandle = context
+            .create_primary(
+                Hierarchy::Endorsement,
+                public_parent,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle
+            .into();
+
+        let (rewrapped_duplicate, rewrapped_secret) = context
+            .rewrap(
+                old_parent_handle,
+                new_parent_handle,
+                duplicate,
+                object_name,
+                secret,
+            )
+            .unwrap();
+
+        // The rewrapped object can be imported under the new parent.
+        context
+            .import(
+                new_parent_handle,
+                Some(data),
+                public,
+                rewrapped_duplicate,
+                rewrapped_secret,
+                SymmetricDefinitionObject::Null,
+            )
+            .expect("Failed to import the rewrapped object");
+    }
+}
@@ -72,6 +72,7 @@ mod test_policy_signed {
                 cp_hash_a,
                 policy_ref,
                 Some(Duration::from_secs(3600)),
+                false,
                 signature,
             )
             .expect("Call to policy_signed failed");
@@ -133,11 +134,131 @@ mod test_policy_secret {
                 cp_hash_a,
                 policy_ref,
                 Some(Duration::from_secs(3600)),
+                false,
             )
             .expect("Failed to call policy_secret");
     }
 }
 
+mod test_policy_ticket {
+    use crate::common::create_ctx_with_session;
+    use std::{convert::TryFrom, time::Duration};
+    use tss_esapi::{
+        constants::SessionType,
+        handles::AuthHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm, reserved_handles::Hierarchy,
+            session_handles::PolicySession,
+        },
+        structures::{Digest, Name, Nonce, SymmetricDefinition, Ticket},
+        Context,
+    };
+
+    fn start_policy_session(context: &mut Context) -> PolicySession {
+        let policy_auth_session = context
+            .execute_without_session(|ctx| {
+                ctx.start_auth_session(
+                    None,
+                    None,
+                    None,
+                    SessionType::Policy,
+                    SymmetricDefinition::AES_256_CFB,
+                    HashingAlgorithm::Sha256,
+                )
+            })
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session")
+    }
+
+    #[test]
+    fn test_policy_ticket_with_null_ticket() {
+        let mut context = create_ctx_with_session();
+
+        let policy_session = start_policy_session(&mut context);
+
+        let cp_hash_a = Digest::try_from(vec![1, 2, 3]).unwrap();
+        let policy_ref = Nonce::try_from(vec![1, 2, 3]).unwrap();
+
+        // A null ticket is returned when no ticket is requested.
+        let (timeout, ticket) = context
+            .policy_secret(
+                policy_session,
+                AuthHandle::Endorsement,
+                Nonce::default(),
+                cp_hash_a.clone(),
+                policy_ref.clone(),
+                Some(Duration::from_secs(3600)),
+                false,
+            )
+            .expect("Failed to call policy_secret");
+        assert_eq!(Hierarchy::Null, ticket.hierarchy());
+
+        let endorsement_name = Name::try_from(0x4000000Bu32.to_be_bytes().to_vec())
+            .expect("Failed to create name of the endorsement hierarchy");
+        let _ = context
+            .execute_without_session(|ctx| {
+                ctx.policy_ticket(
+                    policy_session,
+                    timeout,
+                    cp_hash_a,
+                    policy_ref,
+                    &endorsement_name,
+                    ticket,
+                )
+            })
+            .expect_err("Call to policy_ticket with a null ticket succeeded");
+    }
+
+    #[test]
+    fn test_policy_ticket() {
+        let mut context = create_ctx_with_session();
+
+        let secret_policy_session = start_policy_session(&mut context);
+        let ticket_policy_session = start_policy_session(&mut context);
+
+        let policy_ref = Nonce::try_from(vec![1, 2, 3]).unwrap();
+
+        let (timeout, ticket) = context
+            .policy_secret(
+                secret_policy_session,
+                AuthHandle::Endorsement,
+                Nonce::default(),
+                Digest::default(),
+                policy_ref.clone(),
+                Some(Duration::from_secs(3600)),
+                true,
+            )
+            .expect("Failed to call policy_secret");
+        assert_eq!(Hierarchy::Endorsement, ticket.hierarchy());
+
+        let endorsement_name = Name::try_from(0x4000000Bu32.to_be_bytes().to_vec())
+            .expect("Failed to create name of the endorsement hierarchy");
+        context
+            .execute_without_session(|ctx| {
+                ctx.policy_ticket(
+                    ticket_policy_session,
+                    timeout,
+                    Digest::default(),
+                    policy_ref,
+                    &endorsement_name,
+                    ticket,
+                )
+            })
+            .expect("Call to policy_ticket failed");
+
+        // The ticket extends the policy in the same way as the authorization it replaces.
+        let secret_policy_digest = context
+            .execute_without_session(|ctx| ctx.policy_get_digest(secret_policy_session))
+            .expect("Failed to get the digest of the policy_secret session");
+        let ticket_policy_digest = context
+            .execute_without_session(|ctx| ctx.policy_get_digest(ticket_policy_session))
+            .expect("Failed to get the digest of the policy_ticket session");
+        assert_eq!(secret_policy_digest, ticket_policy_digest);
+    }
+}
+
 mod test_policy_or {
     use crate::common::{create_ctx_without_session, get_pcr_policy_digest};
     use std::convert::TryFrom;
@@ -949,3 +1070,184 @@ mod test_policy_authorize_nv {
         policy_result.unwrap();
     }
 }
+
+mod test_policy_nv {
+    use crate::common::{create_ctx_with_session, write_nv_index};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{ArithmeticComparison, SessionType},
+        handles::{NvIndexHandle, NvIndexTpmHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            reserved_handles::{NvAuth, Provision},
+            session_handles::PolicySession,
+        },
+        structures::{Operand, SymmetricDefinition},
+        Context,
+    };
+
+    fn start_policy_session(context: &mut Context, session_type: SessionType) -> PolicySession {
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                session_type,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session")
+    }
+
+    fn undefine_nv_index(
+        context: &mut Context,
+        nv_index: NvIndexTpmHandle,
+        initial_nv_index_handle: NvIndexHandle,
+    ) {
+        let owner_nv_index_handle = context
+            .tr_from_tpm_public(nv_index.into())
+            .map_or_else(|_| initial_nv_index_handle, NvIndexHandle::from);
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+    }
+
+    #[test]
+    fn test_policy_nv_trial_session() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500501).unwrap();
+        let nv_index_handle = write_nv_index(&mut context, nv_index);
+        let trial_policy_session = start_policy_session(&mut context, SessionType::Trial);
+
+        // The NV index content is not checked for a TRIAL session.
+        let policy_result = context.policy_nv(
+            trial_policy_session,
+            NvAuth::Owner,
+            nv_index_handle,
+            Operand::try_from(vec![0xff, 0xff]).unwrap(),
+            0,
+            ArithmeticComparison::Equal,
+        );
+
+        undefine_nv_index(&mut context, nv_index, nv_index_handle);
+        policy_result.expect("Call to policy_nv failed");
+    }
+
+    #[test]
+    fn test_policy_nv_policy_session() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500502).unwrap();
+        let nv_index_handle = write_nv_index(&mut context, nv_index);
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+
+        // The data written to the NV index starts with [1, 2, 3, 4, 5, 6, 7].
+        let matching_result = context.policy_nv(
+            policy_session,
+            NvAuth::Owner,
+            nv_index_handle,
+            Operand::try_from(vec![3, 4]).unwrap(),
+            2,
+            ArithmeticComparison::Equal,
+        );
+        let mismatching_result = context.policy_nv(
+            policy_session,
+            NvAuth::Owner,
+            nv_index_handle,
+            Operand::try_from(vec![3, 4]).unwrap(),
+            2,
+            ArithmeticComparison::UnsignedGreaterThan,
+        );
+
+        undefine_nv_index(&mut context, nv_index, nv_index_handle);
+        matching_result.expect("Call to policy_nv failed");
+        let _ =
+            mismatching_result.expect_err("Call to policy_nv with a false comparison succeeded");
+    }
+}
+
+mod test_policy_counter_timer {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{ArithmeticComparison, SessionType},
+        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
+        structures::{Digest, Operand, SymmetricDefinition},
+    };
+
+    #[test]
+    fn test_policy_counter_timer_trial_session() {
+        let mut context = create_ctx_without_session();
+        let trial_policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let trial_policy_session = PolicySession::try_from(trial_policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+
+        // The clock value is not checked for a TRIAL session.
+        context
+            .policy_counter_timer(
+                trial_policy_session,
+                Operand::try_from(vec![0xff; 8]).unwrap(),
+                0,
+                ArithmeticComparison::UnsignedGreaterThan,
+            )
+            .expect("Call to policy_counter_timer failed");
+
+        let policy_digest = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to get policy digest for trial session");
+        assert_ne!(
+            Digest::try_from(vec![0; 32]).unwrap(),
+            policy_digest,
+            "The policy digest was not updated"
+        );
+    }
+
+    #[test]
+    fn test_policy_counter_timer_policy_session() {
+        let mut context = create_ctx_without_session();
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Policy,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+
+        // The time value, at offset 0, is always greater than or equal to 0.
+        context
+            .policy_counter_timer(
+                policy_session,
+                Operand::try_from(vec![0; 8]).unwrap(),
+                0,
+                ArithmeticComparison::UnsignedGreaterOrEqual,
+            )
+            .expect("Call to policy_counter_timer failed");
+
+        let _ = context
+            .policy_counter_timer(
+                policy_session,
+                Operand::try_from(vec![0; 8]).unwrap(),
+                0,
+                ArithmeticComparison::UnsignedLessThan,
+            )
+            .expect_err("Call to policy_counter_timer with a false comparison succeeded");
+    }
+}
@@ -1,2 +1,74 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_commit {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::{
+            algorithm::HashingAlgorithm, ecc::EccCurve, reserved_handles::Hierarchy,
+        },
+        structures::{Digest, EcDaaScheme, EccScheme, Public, Signature, SignatureScheme},
+        utils::create_unrestricted_signing_ecc_public,
+    };
+
+    #[test]
+    fn test_commit_and_sign() {
+        let mut context = create_ctx_with_session();
+        let result = context
+            .create_primary(
+                Hierarchy::Owner,
+                create_unrestricted_signing_ecc_public(
+                    EccScheme::EcDaa(EcDaaScheme::new(HashingAlgorithm::Sha256, 0)),
+                    EccCurve::BnP256,
+                )
+                .expect("Failed to create ECDAA signing key public"),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create ECDAA signing key");
+        let public_point = match result.out_public {
+            Public::Ecc { unique, .. } => unique,
+            _ => panic!("Created key is not an ECC key"),
+        };
+
+        let commit_result = context
+            .commit(result.key_handle, Some(public_point), None, None)
+            .expect("Call to commit failed");
+        assert!(commit_result.k.is_none());
+        assert!(commit_result.l.is_none());
+        assert!(!commit_result.e.expect("E was not computed").x().is_empty());
+
+        let signature = context
+            .sign(
+                result.key_handle,
+                Digest::try_from(vec![0xff; 32]).unwrap(),
+                SignatureScheme::EcDaa {
+                    scheme: EcDaaScheme::new(HashingAlgorithm::Sha256, commit_result.counter),
+                },
+                None,
+            )
+            .expect("Failed to sign using the committed value");
+        assert!(matches!(signature, Signature::EcDaa(_)));
+    }
+}
+
+mod test_ec_ephemeral {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::interface_types::ecc::EccCurve;
+
+    #[test]
+    fn test_ec_ephemeral() {
+        let mut context = create_ctx_without_session();
+        let (first_point, first_counter) = context
+            .ec_ephemeral(EccCurve::NistP256)
+            .expect("Call to ec_ephemeral failed");
+        let (second_point, second_counter) = context
+            .ec_ephemeral(EccCurve::NistP256)
+            .expect("Call to ec_ephemeral failed");
+        assert!(!first_point.x().is_empty());
+        assert_ne!(first_point, second_point);
+        assert_ne!(first_counter, second_counter);
+    }
+}
@@ -1,2 +1,37 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_field_upgrade_data {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::structures::MaxBuffer;
+
+    #[test]
+    fn test_field_upgrade_data_without_start() {
+        let mut context = create_ctx_without_session();
+        // Field upgrade data is only accepted after the upgrade has been started.
+        let _ = context
+            .field_upgrade_data(MaxBuffer::try_from(vec![0xff; 16]).unwrap())
+            .unwrap_err();
+    }
+}
+
+mod test_firmware_read {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::return_code::TpmFormatZeroError,
+        error::{TpmFormatZeroResponseCode, TpmResponseCode},
+        Error, ReturnCode,
+    };
+
+    #[test]
+    fn test_firmware_read() {
+        let mut context = create_ctx_without_session();
+        // The command is optional and the simulator does not implement it.
+        match context.firmware_read(0) {
+            Err(Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+                TpmFormatZeroResponseCode::Error(error),
+            )))) if error.error_number() == TpmFormatZeroError::CommandCode => {}
+            result => panic!("Unexpected result of firmware_read: {:?}", result),
+        }
+    }
+}
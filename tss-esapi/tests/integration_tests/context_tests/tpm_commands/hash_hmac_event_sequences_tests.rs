@@ -1,2 +1,241 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_hash_sequence {
+    use crate::common::create_ctx_without_session;
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::{
+            algorithm::HashingAlgorithm, reserved_handles::Hierarchy, session_handles::AuthSession,
+        },
+        structures::{MaxBuffer, Ticket},
+    };
+
+    #[test]
+    fn test_hash_sequence_with_sha_256() {
+        let mut context = create_ctx_without_session();
+        let data = vec![0xa5u8; 3 * MaxBuffer::MAX_SIZE + 17];
+
+        let sequence_handle = context
+            .hash_sequence_start(HashingAlgorithm::Sha256, None)
+            .expect("Call to hash_sequence_start failed");
+
+        let (digest, ticket) = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+            let mut chunks = data.chunks(MaxBuffer::MAX_SIZE).peekable();
+            while let Some(chunk) = chunks.next() {
+                let buffer = MaxBuffer::try_from(chunk.to_vec())
+                    .expect("Failed to create MaxBuffer from chunk");
+                if chunks.peek().is_some() {
+                    ctx.sequence_update(sequence_handle, buffer)
+                        .expect("Call to sequence_update failed");
+                } else {
+                    return ctx
+                        .sequence_complete(sequence_handle, buffer, Hierarchy::Owner)
+                        .expect("Call to sequence_complete failed");
+                }
+            }
+            unreachable!("The data is not empty");
+        });
+
+        assert_eq!(Sha256::digest(&data).as_slice(), digest.as_bytes());
+        assert_eq!(ticket.hierarchy(), Hierarchy::Owner);
+    }
+
+    #[test]
+    fn test_hash_sequence_flushed_on_complete() {
+        let mut context = create_ctx_without_session();
+
+        let sequence_handle = context
+            .hash_sequence_start(HashingAlgorithm::Sha256, None)
+            .expect("Call to hash_sequence_start failed");
+
+        let _ = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.sequence_complete(sequence_handle, MaxBuffer::default(), Hierarchy::Null)
+            })
+            .expect("Call to sequence_complete failed");
+
+        // The sequence object is no longer available.
+        let _ = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.sequence_update(sequence_handle, MaxBuffer::default())
+            })
+            .expect_err("Call to sequence_update succeeded on a completed sequence");
+    }
+}
+
+mod test_hmac_sequence {
+    use crate::common::{create_ctx_with_session, hmac_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::{
+            algorithm::HashingAlgorithm, reserved_handles::Hierarchy, session_handles::AuthSession,
+        },
+        structures::{MaxBuffer, Ticket},
+    };
+
+    #[test]
+    fn test_hmac_sequence() {
+        let mut context = create_ctx_with_session();
+
+        let key = context
+            .create_primary(Hierarchy::Owner, hmac_key_pub(), None, None, None, None)
+            .expect("Failed to create primary key");
+
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+
+        let expected = context
+            .hmac(
+                key.key_handle.into(),
+                MaxBuffer::try_from(data.clone()).unwrap(),
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Call to hmac failed");
+
+        let sequence_handle = context
+            .hmac_start(key.key_handle.into(), None, HashingAlgorithm::Sha256)
+            .expect("Call to hmac_start failed");
+
+        let (actual, ticket) = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.sequence_update(
+                sequence_handle,
+                MaxBuffer::try_from(data[..4].to_vec()).unwrap(),
+            )
+            .expect("Call to sequence_update failed");
+            ctx.sequence_complete(
+                sequence_handle,
+                MaxBuffer::try_from(data[4..].to_vec()).unwrap(),
+                Hierarchy::Owner,
+            )
+            .expect("Call to sequence_complete failed")
+        });
+
+        assert_eq!(expected, actual);
+        // HMAC sequences always produce a null ticket.
+        assert_eq!(ticket.hierarchy(), Hierarchy::Null);
+    }
+}
+
+#[cfg(has_esys_mac)]
+mod test_mac_sequence {
+    use crate::common::{create_ctx_with_session, hmac_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::{
+            algorithm::MacSchemeAlgorithm, reserved_handles::Hierarchy,
+            session_handles::AuthSession,
+        },
+        structures::MaxBuffer,
+    };
+
+    #[test]
+    fn test_mac_sequence() {
+        let mut context = create_ctx_with_session();
+
+        let key = context
+            .create_primary(Hierarchy::Owner, hmac_key_pub(), None, None, None, None)
+            .expect("Failed to create primary key");
+
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+
+        let expected = context
+            .mac(
+                key.key_handle.into(),
+                MaxBuffer::try_from(data.clone()).unwrap(),
+                MacSchemeAlgorithm::Sha256,
+            )
+            .expect("Call to mac failed");
+
+        let sequence_handle = context
+            .mac_start(key.key_handle.into(), None, MacSchemeAlgorithm::Sha256)
+            .expect("Call to mac_start failed");
+
+        let (actual, _) = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.sequence_update(
+                sequence_handle,
+                MaxBuffer::try_from(data[..4].to_vec()).unwrap(),
+            )
+            .expect("Call to sequence_update failed");
+            ctx.sequence_complete(
+                sequence_handle,
+                MaxBuffer::try_from(data[4..].to_vec()).unwrap(),
+                Hierarchy::Owner,
+            )
+            .expect("Call to sequence_complete failed")
+        });
+
+        assert_eq!(expected, actual);
+    }
+}
+
+mod test_event_sequence_complete {
+    use crate::common::create_ctx_without_session;
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::PcrHandle,
+        interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+        structures::MaxBuffer,
+    };
+
+    #[test]
+    fn test_event_sequence_complete() {
+        let mut context = create_ctx_without_session();
+        let data = "There is no spoon".as_bytes().to_vec();
+
+        let sequence_handle = context
+            .hash_sequence_start(HashingAlgorithm::Null, None)
+            .expect("Call to hash_sequence_start failed");
+
+        let digest_values = context.execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| {
+                ctx.event_sequence_complete(
+                    Some(PcrHandle::Pcr16),
+                    sequence_handle,
+                    MaxBuffer::try_from(data.clone()).unwrap(),
+                )
+                .expect("Call to event_sequence_complete failed")
+            },
+        );
+
+        assert_eq!(
+            Sha256::digest(&data).as_slice(),
+            digest_values
+                .get(HashingAlgorithm::Sha256)
+                .expect("Missing SHA256 digest")
+                .as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_event_sequence_complete_without_pcr() {
+        let mut context = create_ctx_without_session();
+
+        let sequence_handle = context
+            .hash_sequence_start(HashingAlgorithm::Null, None)
+            .expect("Call to hash_sequence_start failed");
+
+        let digest_values = context.execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| {
+                ctx.event_sequence_complete(
+                    None,
+                    sequence_handle,
+                    MaxBuffer::try_from(vec![1, 2, 3, 4]).unwrap(),
+                )
+                .expect("Call to event_sequence_complete failed")
+            },
+        );
+
+        assert!(!digest_values.is_empty());
+    }
+}
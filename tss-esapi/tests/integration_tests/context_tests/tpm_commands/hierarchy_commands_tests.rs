@@ -28,6 +28,144 @@ mod test_create_primary {
     }
 }
 
+mod test_hierarchy_control {
+    use crate::common::{create_ctx_with_session, decryption_key_pub};
+    use tss_esapi::interface_types::reserved_handles::{Enables, Hierarchy};
+
+    #[test]
+    fn test_hierarchy_control() {
+        let mut context = create_ctx_with_session();
+
+        context
+            .hierarchy_control(Hierarchy::Platform, Enables::Endorsement, false)
+            .expect("Failed to disable the endorsement hierarchy");
+        assert!(
+            context
+                .create_primary(
+                    Hierarchy::Endorsement,
+                    decryption_key_pub(),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .is_err(),
+            "Created a primary key in a disabled hierarchy"
+        );
+
+        context
+            .hierarchy_control(Hierarchy::Platform, Enables::Endorsement, true)
+            .expect("Failed to enable the endorsement hierarchy");
+        let key_handle = context
+            .create_primary(
+                Hierarchy::Endorsement,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create a primary key in the enabled hierarchy")
+            .key_handle;
+        context.flush_context(key_handle.into()).unwrap();
+    }
+}
+
+mod test_set_primary_policy {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::{algorithm::HashingAlgorithm, reserved_handles::HierarchyPolicy},
+        structures::Digest,
+    };
+
+    #[test]
+    fn test_set_primary_policy() {
+        let mut context = create_ctx_with_session();
+
+        context
+            .set_primary_policy(
+                HierarchyPolicy::Owner,
+                Digest::try_from(vec![0xaa; 32]).unwrap(),
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Failed to set the owner policy");
+        context
+            .set_primary_policy(
+                HierarchyPolicy::Owner,
+                Digest::default(),
+                HashingAlgorithm::Null,
+            )
+            .expect("Failed to remove the owner policy");
+    }
+
+    #[test]
+    fn test_set_primary_policy_with_invalid_digest_size() {
+        let mut context = create_ctx_with_session();
+
+        let _ = context
+            .set_primary_policy(
+                HierarchyPolicy::Owner,
+                Digest::try_from(vec![0xaa; 20]).unwrap(),
+                HashingAlgorithm::Sha256,
+            )
+            .expect_err("Set a policy with a digest that does not match the hashing algorithm");
+    }
+}
+
+mod test_change_seeds {
+    use crate::common::{create_ctx_with_session, decryption_key_pub};
+    use tss_esapi::{
+        interface_types::reserved_handles::{Hierarchy, Platform},
+        structures::Public,
+        Context,
+    };
+
+    fn create_and_flush_primary(context: &mut Context, hierarchy: Hierarchy) -> Public {
+        let result = context
+            .create_primary(hierarchy, decryption_key_pub(), None, None, None, None)
+            .expect("Failed to create primary key");
+        context.flush_context(result.key_handle.into()).unwrap();
+        result.out_public
+    }
+
+    #[test]
+    fn test_change_pps() {
+        let mut context = create_ctx_with_session();
+
+        let before = create_and_flush_primary(&mut context, Hierarchy::Platform);
+        assert_eq!(
+            before,
+            create_and_flush_primary(&mut context, Hierarchy::Platform)
+        );
+        context
+            .change_pps(Platform::Platform)
+            .expect("Failed to change the platform primary seed");
+        assert_ne!(
+            before,
+            create_and_flush_primary(&mut context, Hierarchy::Platform)
+        );
+    }
+
+    #[test]
+    fn test_change_eps() {
+        let mut context = create_ctx_with_session();
+
+        let before = create_and_flush_primary(&mut context, Hierarchy::Endorsement);
+        assert_eq!(
+            before,
+            create_and_flush_primary(&mut context, Hierarchy::Endorsement)
+        );
+        context
+            .change_eps(Platform::Platform)
+            .expect("Failed to change the endorsement primary seed");
+        assert_ne!(
+            before,
+            create_and_flush_primary(&mut context, Hierarchy::Endorsement)
+        );
+    }
+}
+
 mod test_clear {
     use crate::common::create_ctx_with_session;
     use tss_esapi::handles::AuthHandle;
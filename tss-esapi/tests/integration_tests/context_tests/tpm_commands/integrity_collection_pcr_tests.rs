@@ -248,3 +248,133 @@ mod test_pcr_read {
         assert_ne!(pcr_selection_list_in, pcr_selection_list_out);
     }
 }
+
+mod test_pcr_event {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::PcrHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm, reserved_handles::Hierarchy, session_handles::AuthSession,
+        },
+        structures::{Event, MaxBuffer, PcrSelectionListBuilder, PcrSlot},
+    };
+
+    #[test]
+    fn test_pcr_event() {
+        let mut context = create_ctx_without_session();
+        let event_data = "There is no spoon".as_bytes().to_vec();
+        let (expected_digest, _) = context
+            .hash(
+                MaxBuffer::try_from(event_data.clone()).unwrap(),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Null,
+            )
+            .unwrap();
+        let pcr_selection_list = PcrSelectionListBuilder::new()
+            .with_selection(HashingAlgorithm::Sha256, &[PcrSlot::Slot16])
+            .build()
+            .unwrap();
+        let (_, _, pcr_values_before) = context.pcr_read(pcr_selection_list.clone()).unwrap();
+
+        let digests = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.pcr_event(PcrHandle::Pcr16, Event::try_from(event_data).unwrap())
+            })
+            .expect("Call to pcr_event failed");
+
+        assert_eq!(
+            Some(&expected_digest),
+            digests.get(HashingAlgorithm::Sha256)
+        );
+        let (_, _, pcr_values_after) = context.pcr_read(pcr_selection_list).unwrap();
+        assert_ne!(pcr_values_before.value(), pcr_values_after.value());
+    }
+}
+
+mod test_pcr_allocate {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        constants::CapabilityType, interface_types::reserved_handles::Platform,
+        structures::CapabilityData,
+    };
+
+    #[test]
+    fn test_pcr_allocate_current_allocation() {
+        let mut context = create_ctx_with_session();
+        let current_allocation = match context
+            .get_capability(CapabilityType::AssignedPcr, 0, 80)
+            .expect("Failed to get the assigned PCRs")
+        {
+            (CapabilityData::AssignedPcr(pcr_selection_list), _) => pcr_selection_list,
+            _ => panic!("Unexpected capability data"),
+        };
+
+        let result = context
+            .pcr_allocate(Platform::Platform, current_allocation)
+            .expect("Call to pcr_allocate failed");
+        assert!(result.allocation_success);
+        assert!(result.max_pcr > 0);
+        assert!(result.size_needed <= result.size_available);
+    }
+}
+
+mod test_pcr_set_auth {
+    use crate::common::{create_ctx_with_session, create_ctx_without_session};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::{ObjectHandle, PcrHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm, reserved_handles::Platform, session_handles::AuthSession,
+        },
+        structures::{Auth, Digest, Event},
+    };
+
+    #[test]
+    fn test_pcr_set_auth_policy() {
+        let mut context = create_ctx_with_session();
+        // PCR 20 to 22 are in the policy group of the reference implementation.
+        context
+            .pcr_set_auth_policy(
+                Platform::Platform,
+                Digest::try_from(vec![0xaa; 32]).unwrap(),
+                HashingAlgorithm::Sha256,
+                PcrHandle::Pcr20,
+            )
+            .expect("Failed to set the PCR policy");
+        context
+            .pcr_set_auth_policy(
+                Platform::Platform,
+                Digest::default(),
+                HashingAlgorithm::Null,
+                PcrHandle::Pcr20,
+            )
+            .expect("Failed to remove the PCR policy");
+    }
+
+    #[test]
+    fn test_pcr_set_auth_value() {
+        let mut context = create_ctx_without_session();
+        let new_auth = Auth::try_from(vec![1, 2, 3, 4]).unwrap();
+        let event = Event::try_from(vec![0xff; 16]).unwrap();
+        context.execute_with_session(Some(AuthSession::Password), |ctx| {
+            // PCR 20 to 22 are in the authorization group of the reference implementation.
+            ctx.pcr_set_auth_value(PcrHandle::Pcr20, new_auth.clone())
+                .expect("Failed to set the PCR auth value");
+            let _ = ctx
+                .pcr_event(PcrHandle::Pcr20, event.clone())
+                .expect_err("PCR was extended using the old auth value");
+
+            ctx.tr_set_auth(ObjectHandle::from(PcrHandle::Pcr20), new_auth)
+                .unwrap();
+            let _ = ctx
+                .pcr_event(PcrHandle::Pcr20, event)
+                .expect("Failed to extend the PCR using the new auth value");
+
+            ctx.pcr_set_auth_value(PcrHandle::Pcr20, Auth::default())
+                .expect("Failed to reset the PCR auth value");
+            ctx.tr_set_auth(ObjectHandle::from(PcrHandle::Pcr20), Auth::default())
+                .unwrap();
+        });
+    }
+}
@@ -1,2 +1,66 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_pp_commands {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{CapabilityType, CommandCode},
+        interface_types::reserved_handles::Platform,
+        structures::{CapabilityData, CommandCodeList},
+        Context,
+    };
+
+    fn get_pp_commands(context: &mut Context) -> CommandCodeList {
+        let (capability_data, _) = context
+            .get_capability(
+                CapabilityType::PpCommands,
+                0,
+                CommandCodeList::MAX_SIZE as u32,
+            )
+            .expect("Failed to get capability");
+        if let CapabilityData::PpCommands(pp_commands) = capability_data {
+            pp_commands
+        } else {
+            panic!("Got wrong type of capability data: {:?}", capability_data);
+        }
+    }
+
+    #[test]
+    fn test_pp_commands() {
+        let mut context = create_ctx_with_session();
+        let command_list = CommandCodeList::try_from(vec![CommandCode::ClearControl])
+            .expect("Failed to create command code list");
+
+        context
+            .pp_commands(Platform::Platform, command_list.clone(), Default::default())
+            .expect("Failed to set physical presence commands");
+        assert!(get_pp_commands(&mut context).contains(&CommandCode::ClearControl));
+
+        context
+            .pp_commands(Platform::Platform, Default::default(), command_list)
+            .expect("Failed to clear physical presence commands");
+        assert!(!get_pp_commands(&mut context).contains(&CommandCode::ClearControl));
+    }
+}
+
+mod test_set_algorithm_set {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        constants::return_code::TpmFormatZeroError,
+        error::{TpmFormatZeroResponseCode, TpmResponseCode},
+        interface_types::reserved_handles::Platform,
+        Error, ReturnCode,
+    };
+
+    #[test]
+    fn test_set_algorithm_set() {
+        let mut context = create_ctx_with_session();
+        // The command is optional and the simulator does not implement it.
+        match context.set_algorithm_set(Platform::Platform, 0) {
+            Err(Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+                TpmFormatZeroResponseCode::Error(error),
+            )))) if error.error_number() == TpmFormatZeroError::CommandCode => {}
+            result => panic!("Unexpected result of set_algorithm_set: {:?}", result),
+        }
+    }
+}
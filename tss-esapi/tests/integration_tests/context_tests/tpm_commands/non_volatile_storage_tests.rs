@@ -421,3 +421,556 @@ mod test_nv_increment {
         assert_eq!(first_value + 1, second_value);
     }
 }
+
+mod test_nv_extend {
+    use crate::common::create_ctx_with_session;
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::nv_index_type::NvIndexType,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            reserved_handles::{NvAuth, Provision},
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder},
+    };
+
+    #[test]
+    fn test_nv_extend() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500503).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_nv_index_type(NvIndexType::Extend)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let value = [1, 2, 3, 4, 5, 6, 7];
+        let data = MaxNvBuffer::try_from(value.to_vec()).expect("Failed to create MaxNvBuffer");
+
+        // Extend the data using Owner authorization
+        let nv_extend_result = context.nv_extend(NvAuth::Owner, owner_nv_index_handle, data);
+
+        // Read the extended value using owner authorization
+        let nv_read_result = context.nv_read(NvAuth::Owner, owner_nv_index_handle, 32, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        nv_extend_result.expect("Call to nv_extend failed");
+        let actual_value = nv_read_result.expect("Call to nv_read failed");
+
+        // The index is initialized with zeros before the first extend.
+        let mut hasher = Sha256::new();
+        hasher.update([0u8; 32]);
+        hasher.update(value);
+        assert_eq!(actual_value.as_slice(), hasher.finalize().as_slice());
+    }
+}
+
+mod test_nv_set_bits {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryInto;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::nv_index_type::NvIndexType,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            reserved_handles::{NvAuth, Provision},
+        },
+        structures::NvPublicBuilder,
+    };
+
+    #[test]
+    fn test_nv_set_bits() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500504).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_nv_index_type(NvIndexType::Bits)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(8)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // Set bits using Owner authorization
+        let first_nv_set_bits_result =
+            context.nv_set_bits(NvAuth::Owner, owner_nv_index_handle, 0b0101);
+        let second_nv_set_bits_result =
+            context.nv_set_bits(NvAuth::Owner, owner_nv_index_handle, 0b1000_0000);
+
+        // Read the bit field using owner authorization
+        let nv_read_result = context.nv_read(NvAuth::Owner, owner_nv_index_handle, 8, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        first_nv_set_bits_result.expect("First call to nv_set_bits failed");
+        second_nv_set_bits_result.expect("Second call to nv_set_bits failed");
+        let nv_read_value = nv_read_result.expect("Call to nv_read failed");
+
+        // The bit field is an 8 byte unsigned big-endian value.
+        let actual_value = u64::from_be_bytes(
+            nv_read_value
+                .to_vec()
+                .try_into()
+                .expect("Failed to convert nv_read_value as a vector into an 8 byte array"),
+        );
+        assert_eq!(0b1000_0101, actual_value);
+    }
+}
+
+mod test_nv_write_lock {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            reserved_handles::{NvAuth, Provision},
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder},
+    };
+
+    #[test]
+    fn test_nv_write_lock() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500505).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_write_define(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+            .expect("Failed to create MaxNvBuffer from data");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let first_nv_write_result =
+            context.nv_write(NvAuth::Owner, owner_nv_index_handle, data.clone(), 0);
+        let nv_write_lock_result = context.nv_write_lock(NvAuth::Owner, owner_nv_index_handle);
+        // Writing to a locked index should fail.
+        let second_nv_write_result =
+            context.nv_write(NvAuth::Owner, owner_nv_index_handle, data, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        first_nv_write_result.expect("First call to nv_write failed");
+        nv_write_lock_result.expect("Call to nv_write_lock failed");
+        let _ = second_nv_write_result.expect_err("Writing to a write locked index did not fail");
+    }
+
+    #[test]
+    fn test_nv_global_write_lock() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500506).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_global_lock(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+            .expect("Failed to create MaxNvBuffer from data");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let nv_global_write_lock_result = context.nv_global_write_lock(Provision::Owner);
+        // Writing to an index with the global lock attribute should fail.
+        let nv_write_result = context.nv_write(NvAuth::Owner, owner_nv_index_handle, data, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        nv_global_write_lock_result.expect("Call to nv_global_write_lock failed");
+        let _ = nv_write_result.expect_err("Writing to a globally write locked index did not fail");
+    }
+}
+
+mod test_nv_read_lock {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            reserved_handles::{NvAuth, Provision},
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder},
+    };
+
+    #[test]
+    fn test_nv_read_lock() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500507).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_read_stclear(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let value = [1, 2, 3, 4, 5, 6, 7];
+        let data =
+            MaxNvBuffer::try_from(value.to_vec()).expect("Failed to create MaxNvBuffer from data");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let nv_write_result = context.nv_write(NvAuth::Owner, owner_nv_index_handle, data, 0);
+        let first_nv_read_result =
+            context.nv_read(NvAuth::Owner, owner_nv_index_handle, value.len() as u16, 0);
+        let nv_read_lock_result = context.nv_read_lock(NvAuth::Owner, owner_nv_index_handle);
+        // Reading a locked index should fail.
+        let second_nv_read_result =
+            context.nv_read(NvAuth::Owner, owner_nv_index_handle, value.len() as u16, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        nv_write_result.expect("Call to nv_write failed");
+        let _ = first_nv_read_result.expect("First call to nv_read failed");
+        nv_read_lock_result.expect("Call to nv_read_lock failed");
+        let _ = second_nv_read_result.expect_err("Reading a read locked index did not fail");
+    }
+}
+
+mod test_nv_change_auth {
+    use crate::common::{create_ctx_with_session, create_ctx_without_session};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::{NvIndexAttributesBuilder, SessionAttributesBuilder},
+        constants::{CommandCode, SessionType},
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            reserved_handles::{NvAuth, Provision},
+            session_handles::{AuthSession, PolicySession},
+        },
+        structures::{Auth, MaxNvBuffer, NvPublicBuilder, SymmetricDefinition},
+        Context,
+    };
+
+    fn start_policy_session(context: &mut Context, session_type: SessionType) -> PolicySession {
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                session_type,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let (policy_auth_session_attributes, policy_auth_session_attributes_mask) =
+            SessionAttributesBuilder::new()
+                .with_decrypt(true)
+                .with_encrypt(true)
+                .build();
+        context
+            .tr_sess_set_attributes(
+                policy_auth_session,
+                policy_auth_session_attributes,
+                policy_auth_session_attributes_mask,
+            )
+            .expect("tr_sess_set_attributes call failed");
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+        context
+            .policy_command_code(policy_session, CommandCode::NvChangeAuth)
+            .expect("Call to policy_command_code failed");
+        context
+            .policy_auth_value(policy_session)
+            .expect("Call to policy_auth_value failed");
+        policy_session
+    }
+
+    #[test]
+    fn test_nv_change_auth() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500508).unwrap();
+
+        // Compute the policy digest that allows the auth value to be changed.
+        let mut trial_context = create_ctx_without_session();
+        let trial_policy_session = start_policy_session(&mut trial_context, SessionType::Trial);
+        let policy_digest = trial_context
+            .policy_get_digest(trial_policy_session)
+            .expect("Call to policy_get_digest failed");
+
+        // Create nv public that uses the auth value of the index.
+        let nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_auth_write(true)
+            .with_auth_read(true)
+            .build()
+            .expect("Failed to create nv index attributes");
+
+        let nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(nv_index_attributes)
+            .with_index_auth_policy(policy_digest)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic");
+
+        let initial_auth = Auth::try_from(vec![1, 2, 3, 4]).expect("Failed to create auth");
+        let new_auth = Auth::try_from(vec![5, 6, 7, 8]).expect("Failed to create auth");
+        let value = [1, 2, 3, 4, 5, 6, 7];
+        let data =
+            MaxNvBuffer::try_from(value.to_vec()).expect("Failed to create MaxNvBuffer from data");
+
+        let nv_index_handle = context
+            .nv_define_space(Provision::Owner, Some(initial_auth), nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        let nv_change_auth_result = context
+            .execute_with_session(Some(AuthSession::from(policy_session)), |ctx| {
+                ctx.nv_change_auth(nv_index_handle, new_auth)
+            });
+
+        // The new auth value is used when authorizing the index.
+        let nv_write_result = context.nv_write(
+            NvAuth::NvIndex(nv_index_handle),
+            nv_index_handle,
+            data.clone(),
+            0,
+        );
+        let nv_read_result = context.nv_read(
+            NvAuth::NvIndex(nv_index_handle),
+            nv_index_handle,
+            value.len() as u16,
+            0,
+        );
+
+        context
+            .nv_undefine_space(Provision::Owner, nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        nv_change_auth_result.expect("Call to nv_change_auth failed");
+        nv_write_result.expect("Call to nv_write failed");
+        let actual_data = nv_read_result.expect("Call to nv_read failed");
+        assert_eq!(data, actual_data);
+    }
+}
+
+mod test_nv_certify {
+    use crate::common::{create_ctx_with_session, signing_key_pub, write_nv_index};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::StructureTag,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            reserved_handles::{Hierarchy, NvAuth, Provision},
+            session_handles::AuthSession,
+            structure_tags::AttestationType,
+        },
+        structures::{AttestInfo, Data, MaxBuffer, SignatureScheme, Ticket},
+        traits::Marshall,
+    };
+
+    #[test]
+    fn test_nv_certify() {
+        let mut context = create_ctx_with_session();
+        let qualifying_data = vec![0xff; 16];
+
+        let sign_key_handle = context
+            .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+        let nv_index_handle = write_nv_index(
+            &mut context,
+            NvIndexTpmHandle::new(0x01500509).expect("Failed to create NV index tpm handle"),
+        );
+        let (_, nv_index_name) = context
+            .nv_read_public(nv_index_handle)
+            .expect("Call to nv_read_public failed");
+
+        let nv_certify_result = context.execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| {
+                ctx.nv_certify(
+                    sign_key_handle,
+                    NvAuth::Owner,
+                    nv_index_handle,
+                    Data::try_from(qualifying_data.clone()).unwrap(),
+                    SignatureScheme::Null,
+                    7,
+                    0,
+                )
+            },
+        );
+
+        context
+            .nv_undefine_space(Provision::Owner, nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        let (attest, signature) = nv_certify_result.expect("Call to nv_certify failed");
+
+        // Verify the signature is valid for the attestation data
+        let data = MaxBuffer::try_from(attest.marshall().unwrap())
+            .expect("Failed to get data buffer from attestation data");
+        let (digest, _) = context
+            .hash(data, HashingAlgorithm::Sha256, Hierarchy::Null)
+            .expect("Failed to hash data");
+        let ticket = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.verify_signature(sign_key_handle, digest, signature)
+            })
+            .expect("Failed to verify signature");
+        assert_eq!(ticket.tag(), StructureTag::Verified);
+
+        // Verify the attestation data is as expected
+        assert_eq!(attest.attestation_type(), AttestationType::Nv);
+        assert_eq!(attest.extra_data().as_bytes(), qualifying_data);
+        match attest.attested() {
+            AttestInfo::Nv { info } => {
+                assert_eq!(&nv_index_name, info.index_name());
+                assert_eq!(0, info.offset());
+                assert_eq!(&[1, 2, 3, 4, 5, 6, 7], info.nv_contents().as_slice());
+            }
+            _ => panic!("Attested did not contain the expected variant."),
+        }
+    }
+
+    #[cfg(has_tpmu_attest_nv_digest)]
+    #[test]
+    fn test_nv_certify_digest() {
+        let mut context = create_ctx_with_session();
+
+        let sign_key_handle = context
+            .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+        let nv_index_handle = write_nv_index(
+            &mut context,
+            NvIndexTpmHandle::new(0x0150050A).expect("Failed to create NV index tpm handle"),
+        );
+        let (_, nv_index_name) = context
+            .nv_read_public(nv_index_handle)
+            .expect("Call to nv_read_public failed");
+
+        // A size and offset of zero makes the TPM certify the digest of the contents.
+        let nv_certify_result = context.execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| {
+                ctx.nv_certify(
+                    sign_key_handle,
+                    NvAuth::Owner,
+                    nv_index_handle,
+                    Data::default(),
+                    SignatureScheme::Null,
+                    0,
+                    0,
+                )
+            },
+        );
+
+        context
+            .nv_undefine_space(Provision::Owner, nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        let (attest, _) = nv_certify_result.expect("Call to nv_certify failed");
+        assert_eq!(attest.attestation_type(), AttestationType::NvDigest);
+        match attest.attested() {
+            AttestInfo::NvDigest { info } => {
+                assert_eq!(&nv_index_name, info.index_name());
+                assert!(!info.nv_digest().is_empty(), "The NV digest is empty");
+            }
+            _ => panic!("Attested did not contain the expected variant."),
+        }
+    }
+}
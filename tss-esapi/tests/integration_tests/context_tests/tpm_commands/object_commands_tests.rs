@@ -427,3 +427,170 @@ mod test_unseal {
         assert!(unsealed == testbytes);
     }
 }
+
+mod test_create_loaded {
+    use crate::common::{create_ctx_with_session, decryption_key_pub, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        handles::ObjectHandle,
+        interface_types::{
+            algorithm::{HashingAlgorithm, KeyDerivationFunction, PublicAlgorithm},
+            ecc::EccCurve,
+            reserved_handles::Hierarchy,
+        },
+        structures::{
+            Auth, Derive, Digest, EccScheme, HashScheme, KeyedHashScheme, Name, PublicBuilder,
+            PublicKeyedHashParameters, TemplateBuffer, XorScheme,
+        },
+        utils::create_unrestricted_signing_ecc_public,
+        Context,
+    };
+
+    #[test]
+    fn test_create_loaded() {
+        let mut context = create_ctx_with_session();
+        let mut random_digest = vec![0u8; 16];
+        getrandom::getrandom(&mut random_digest).unwrap();
+        let key_auth = Auth::from_bytes(random_digest.as_slice()).unwrap();
+
+        let prim_key_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                decryption_key_pub(),
+                Some(key_auth.clone()),
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+
+        let (key_handle, private, public) = context
+            .create_loaded(
+                prim_key_handle.into(),
+                TemplateBuffer::try_from(signing_key_pub())
+                    .expect("Failed to create template from public"),
+                Some(key_auth),
+                None,
+            )
+            .expect("Call to create_loaded failed");
+
+        let (read_public, _, _) = context
+            .read_public(key_handle)
+            .expect("Call to read_public failed");
+        assert_eq!(public, read_public);
+        assert_eq!(
+            signing_key_pub().object_attributes(),
+            public.object_attributes()
+        );
+
+        // The private part can be loaded under the same parent.
+        context
+            .flush_context(key_handle.into())
+            .expect("Call to flush_context failed");
+        let _ = context
+            .load(prim_key_handle, private, public)
+            .expect("Call to load failed");
+    }
+
+    #[test]
+    fn test_create_loaded_primary() {
+        let mut context = create_ctx_with_session();
+
+        let (key_handle, _, public) = context
+            .create_loaded(
+                ObjectHandle::from(Hierarchy::Owner),
+                TemplateBuffer::try_from(decryption_key_pub())
+                    .expect("Failed to create template from public"),
+                None,
+                None,
+            )
+            .expect("Call to create_loaded failed");
+
+        let (read_public, _, _) = context
+            .read_public(key_handle)
+            .expect("Call to read_public failed");
+        assert_eq!(public, read_public);
+    }
+
+    fn create_derived_key(
+        context: &mut Context,
+        derivation_parent: ObjectHandle,
+        derive: Derive,
+    ) -> Name {
+        let signing_key_pub = create_unrestricted_signing_ecc_public(
+            EccScheme::EcDsa(HashScheme::new(HashingAlgorithm::Sha256)),
+            EccCurve::NistP256,
+        )
+        .expect("Failed to create ECC signing public");
+        let (key_handle, _, _) = context
+            .create_loaded(
+                derivation_parent,
+                TemplateBuffer::with_derive(signing_key_pub, derive)
+                    .expect("Failed to create template with derive"),
+                None,
+                None,
+            )
+            .expect("Call to create_loaded failed");
+        let (_, name, _) = context
+            .read_public(key_handle)
+            .expect("Call to read_public failed");
+        context
+            .flush_context(key_handle.into())
+            .expect("Call to flush_context failed");
+        name
+    }
+
+    #[test]
+    fn test_create_loaded_derived() {
+        let mut context = create_ctx_with_session();
+
+        // A derivation parent is a restricted decryption keyed hash object.
+        let derivation_parent_pub = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(
+                ObjectAttributesBuilder::new()
+                    .with_fixed_tpm(true)
+                    .with_fixed_parent(true)
+                    .with_sensitive_data_origin(true)
+                    .with_user_with_auth(true)
+                    .with_restricted(true)
+                    .with_decrypt(true)
+                    .build()
+                    .expect("Failed to build object attributes"),
+            )
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(KeyedHashScheme::Xor {
+                xor_scheme: XorScheme::new(
+                    HashingAlgorithm::Sha256,
+                    KeyDerivationFunction::Kdf1Sp800_108,
+                ),
+            }))
+            .with_keyed_hash_unique_identifier(Digest::default())
+            .build()
+            .expect("Failed to build derivation parent public");
+
+        let (derivation_parent, _, _) = context
+            .create_loaded(
+                ObjectHandle::from(Hierarchy::Owner),
+                TemplateBuffer::try_from(derivation_parent_pub)
+                    .expect("Failed to create template from public"),
+                None,
+                None,
+            )
+            .expect("Call to create_loaded failed");
+
+        let derive =
+            Derive::new(b"label".to_vec(), b"context".to_vec()).expect("Failed to create Derive");
+        let other_derive = Derive::new(b"label".to_vec(), b"other context".to_vec())
+            .expect("Failed to create Derive");
+
+        // Deriving with the same label and context results in the same key.
+        let first_name = create_derived_key(&mut context, derivation_parent.into(), derive.clone());
+        let second_name = create_derived_key(&mut context, derivation_parent.into(), derive);
+        let other_name = create_derived_key(&mut context, derivation_parent.into(), other_derive);
+        assert_eq!(first_name, second_name);
+        assert_ne!(first_name, other_name);
+    }
+}
@@ -182,48 +182,125 @@ mod test_hash {
 }
 
 mod test_hmac {
-    use crate::common::create_ctx_with_session;
+    use crate::common::{create_ctx_with_session, hmac_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::{algorithm::HashingAlgorithm, reserved_handles::Hierarchy},
+        structures::MaxBuffer,
+    };
+
+    #[test]
+    fn test_hmac() {
+        let mut context = create_ctx_with_session();
+
+        let key = context
+            .create_primary(Hierarchy::Owner, hmac_key_pub(), None, None, None, None)
+            .unwrap();
+
+        let data = vec![1, 2, 3, 4];
+
+        let buf = MaxBuffer::try_from(data).unwrap();
+        context
+            .hmac(key.key_handle.into(), buf, HashingAlgorithm::Sha256)
+            .unwrap();
+    }
+}
+
+#[cfg(has_esys_mac)]
+mod test_mac {
+    use crate::common::{create_ctx_with_session, hmac_key_pub};
     use std::convert::TryFrom;
     use tss_esapi::{
         attributes::ObjectAttributesBuilder,
         interface_types::{
-            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            algorithm::{HashingAlgorithm, MacSchemeAlgorithm, PublicAlgorithm, SymmetricMode},
+            key_bits::AesKeyBits,
             reserved_handles::Hierarchy,
         },
-        structures::{KeyedHashScheme, MaxBuffer, PublicBuilder, PublicKeyedHashParameters},
+        structures::{
+            MaxBuffer, PublicBuilder, SensitiveData, SymmetricCipherParameters,
+            SymmetricDefinitionObject,
+        },
     };
 
     #[test]
-    fn test_hmac() {
+    fn test_mac_with_hmac_key() {
+        let mut context = create_ctx_with_session();
+
+        let key = context
+            .create_primary(Hierarchy::Owner, hmac_key_pub(), None, None, None, None)
+            .expect("Failed to create primary key");
+
+        let data = vec![1, 2, 3, 4];
+
+        let expected = context
+            .hmac(
+                key.key_handle.into(),
+                MaxBuffer::try_from(data.clone()).unwrap(),
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Call to hmac failed");
+        let actual = context
+            .mac(
+                key.key_handle.into(),
+                MaxBuffer::try_from(data).unwrap(),
+                MacSchemeAlgorithm::Sha256,
+            )
+            .expect("Call to mac failed");
+        assert_eq!(expected, actual);
+    }
+    #[test]
+    fn test_mac_with_cmac_key() {
         let mut context = create_ctx_with_session();
 
         let object_attributes = ObjectAttributesBuilder::new()
             .with_sign_encrypt(true)
-            .with_sensitive_data_origin(true)
             .with_user_with_auth(true)
             .build()
             .expect("Failed to build object attributes");
 
         let key_pub = PublicBuilder::new()
-            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_public_algorithm(PublicAlgorithm::SymCipher)
             .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
             .with_object_attributes(object_attributes)
-            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
-                KeyedHashScheme::HMAC_SHA_256,
+            .with_symmetric_cipher_parameters(SymmetricCipherParameters::new(
+                SymmetricDefinitionObject::Aes {
+                    key_bits: AesKeyBits::Aes128,
+                    mode: SymmetricMode::Null,
+                },
             ))
-            .with_keyed_hash_unique_identifier(Default::default())
+            .with_symmetric_cipher_unique_identifier(Default::default())
             .build()
             .expect("Failed to build public structure for key.");
 
-        let key = context
-            .create_primary(Hierarchy::Owner, key_pub, None, None, None, None)
-            .unwrap();
+        // Key and message of the second example of RFC 4493.
+        let key_data = SensitiveData::try_from(vec![
+            0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf,
+            0x4f, 0x3c,
+        ])
+        .expect("Failed to create sensitive data");
+        let data = vec![
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93,
+            0x17, 0x2a,
+        ];
 
-        let data = vec![1, 2, 3, 4];
+        let key = context
+            .create_primary(Hierarchy::Owner, key_pub, None, Some(key_data), None, None)
+            .expect("Failed to create primary key");
 
-        let buf = MaxBuffer::try_from(data).unwrap();
-        context
-            .hmac(key.key_handle.into(), buf, HashingAlgorithm::Sha256)
-            .unwrap();
+        let mac = context
+            .mac(
+                key.key_handle.into(),
+                MaxBuffer::try_from(data).unwrap(),
+                MacSchemeAlgorithm::Cmac,
+            )
+            .expect("Call to mac failed");
+        assert_eq!(
+            &[
+                0x07, 0x0a, 0x16, 0xb4, 0x6b, 0x4d, 0x41, 0x44, 0xf7, 0x9b, 0xdd, 0x9d, 0xd0, 0x4a,
+                0x28, 0x7c,
+            ],
+            mac.as_bytes()
+        );
     }
 }
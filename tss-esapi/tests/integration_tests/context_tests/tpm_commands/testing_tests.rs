@@ -20,3 +20,30 @@ mod test_get_test_result {
         rc.unwrap();
     }
 }
+
+mod test_incremental_self_test {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{constants::AlgorithmIdentifier, structures::AlgorithmList};
+
+    #[test]
+    fn test_incremental_self_test() {
+        let mut context = create_ctx_without_session();
+        let requested = vec![
+            AlgorithmIdentifier::Rsa,
+            AlgorithmIdentifier::Sha256,
+            AlgorithmIdentifier::Aes,
+        ];
+        let to_test = AlgorithmList::try_from(requested.clone()).unwrap();
+        let to_do_list = context.incremental_self_test(to_test).unwrap();
+        // The simulator tests the algorithms before responding, so none
+        // of the requested algorithms remain to be tested.
+        for algorithm in requested {
+            assert!(
+                !to_do_list.contains(&algorithm),
+                "{:?} is still on the to-do list",
+                algorithm
+            );
+        }
+    }
+}
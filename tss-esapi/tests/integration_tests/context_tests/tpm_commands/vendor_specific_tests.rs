@@ -1,2 +1,18 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_vendor_tcg_test {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::structures::Data;
+
+    #[test]
+    fn test_vendor_tcg_test() {
+        let mut context = create_ctx_without_session();
+        let input_data = Data::try_from(vec![1, 2, 3, 4]).expect("Failed to create data");
+        // The simulator returns the input data unchanged.
+        let output_data = context
+            .vendor_tcg_test(input_data.clone())
+            .expect("Call to vendor_tcg_test failed");
+        assert_eq!(input_data, output_data);
+    }
+}
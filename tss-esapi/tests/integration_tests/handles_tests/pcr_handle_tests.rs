@@ -2,14 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 use std::convert::{From, TryFrom};
 use tss_esapi::{
-    handles::{ObjectHandle, PcrHandle},
+    constants::tss::TPM2_PCR_FIRST,
+    handles::{ObjectHandle, PcrHandle, PcrTpmHandle},
     tss2_esys::{
         ESYS_TR, ESYS_TR_PCR0, ESYS_TR_PCR1, ESYS_TR_PCR10, ESYS_TR_PCR11, ESYS_TR_PCR12,
         ESYS_TR_PCR13, ESYS_TR_PCR14, ESYS_TR_PCR15, ESYS_TR_PCR16, ESYS_TR_PCR17, ESYS_TR_PCR18,
         ESYS_TR_PCR19, ESYS_TR_PCR2, ESYS_TR_PCR20, ESYS_TR_PCR21, ESYS_TR_PCR22, ESYS_TR_PCR23,
         ESYS_TR_PCR24, ESYS_TR_PCR25, ESYS_TR_PCR26, ESYS_TR_PCR27, ESYS_TR_PCR28, ESYS_TR_PCR29,
         ESYS_TR_PCR3, ESYS_TR_PCR30, ESYS_TR_PCR31, ESYS_TR_PCR4, ESYS_TR_PCR5, ESYS_TR_PCR6,
-        ESYS_TR_PCR7, ESYS_TR_PCR8, ESYS_TR_PCR9,
+        ESYS_TR_PCR7, ESYS_TR_PCR8, ESYS_TR_PCR9, TPM2_HANDLE,
     },
 };
 
@@ -86,3 +87,49 @@ fn test_conversion_of_valid_handle() {
     test_valid_conversions!(ESYS_TR_PCR30, PcrHandle::Pcr30);
     test_valid_conversions!(ESYS_TR_PCR31, PcrHandle::Pcr31);
 }
+
+#[test]
+fn test_conversion_to_pcr_tpm_handle() {
+    let pcr_handles = [
+        PcrHandle::Pcr0,
+        PcrHandle::Pcr1,
+        PcrHandle::Pcr2,
+        PcrHandle::Pcr3,
+        PcrHandle::Pcr4,
+        PcrHandle::Pcr5,
+        PcrHandle::Pcr6,
+        PcrHandle::Pcr7,
+        PcrHandle::Pcr8,
+        PcrHandle::Pcr9,
+        PcrHandle::Pcr10,
+        PcrHandle::Pcr11,
+        PcrHandle::Pcr12,
+        PcrHandle::Pcr13,
+        PcrHandle::Pcr14,
+        PcrHandle::Pcr15,
+        PcrHandle::Pcr16,
+        PcrHandle::Pcr17,
+        PcrHandle::Pcr18,
+        PcrHandle::Pcr19,
+        PcrHandle::Pcr20,
+        PcrHandle::Pcr21,
+        PcrHandle::Pcr22,
+        PcrHandle::Pcr23,
+        PcrHandle::Pcr24,
+        PcrHandle::Pcr25,
+        PcrHandle::Pcr26,
+        PcrHandle::Pcr27,
+        PcrHandle::Pcr28,
+        PcrHandle::Pcr29,
+        PcrHandle::Pcr30,
+        PcrHandle::Pcr31,
+    ];
+    for (pcr_number, pcr_handle) in (0u32..).zip(pcr_handles.iter()) {
+        assert_eq!(
+            TPM2_PCR_FIRST + pcr_number,
+            TPM2_HANDLE::from(PcrTpmHandle::from(*pcr_handle)),
+            "PCR TPM handle conversion failed for {:?}",
+            pcr_handle,
+        );
+    }
+}
@@ -1,11 +1,17 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use std::convert::TryFrom;
+#[cfg(has_esys_act)]
+use tss_esapi::{
+    constants::tss::TPM2_RH_ACT_0,
+    interface_types::reserved_handles::Act,
+    tss2_esys::{ESYS_TR_RH_ACT_FIRST, ESYS_TR_RH_ACT_LAST},
+};
 use tss_esapi::{
     handles::{AuthHandle, NvIndexHandle, ObjectHandle, PermanentTpmHandle, TpmHandle},
     interface_types::reserved_handles::{
-        Clear, Enables, Endorsement, Hierarchy, HierarchyAuth, Lockout, NvAuth, Owner, Platform,
-        Provision,
+        Clear, Enables, Endorsement, Hierarchy, HierarchyAuth, HierarchyPolicy, Lockout, NvAuth,
+        Owner, Platform, Provision,
     },
     tss2_esys::ESYS_TR,
 };
@@ -162,6 +168,115 @@ mod test_hierarchy_auth {
     }
 }
 
+mod test_hierarchy_policy {
+    use super::*;
+    #[test]
+    fn test_conversions() {
+        let test_conversion = |hierarchy_policy: HierarchyPolicy,
+                               tpm_rh: TpmHandle,
+                               esys_rh: ObjectHandle,
+                               name: &str| {
+            assert_eq!(ObjectHandle::from(hierarchy_policy), esys_rh);
+            assert_eq!(TpmHandle::from(hierarchy_policy), tpm_rh);
+            let from_esys_rh = HierarchyPolicy::try_from(esys_rh).unwrap_or_else(|_| {
+                panic!("Failed to create HierarchyPolicy from ESYS_TR_RH={}", name)
+            });
+            assert_eq!(from_esys_rh, hierarchy_policy);
+            assert_eq!(ObjectHandle::from(from_esys_rh), esys_rh);
+            assert_eq!(TpmHandle::from(from_esys_rh), tpm_rh);
+            let from_tpm_rh = HierarchyPolicy::try_from(tpm_rh).unwrap_or_else(|_| {
+                panic!("Failed to create HierarchyPolicy from TPM2_RH={}", name)
+            });
+            assert_eq!(from_tpm_rh, hierarchy_policy);
+            assert_eq!(ObjectHandle::from(from_tpm_rh), esys_rh);
+            assert_eq!(TpmHandle::from(from_tpm_rh), tpm_rh);
+        };
+
+        test_conversion(
+            HierarchyPolicy::Owner,
+            TpmHandle::Permanent(PermanentTpmHandle::Owner),
+            ObjectHandle::Owner,
+            "OWNER",
+        );
+        test_conversion(
+            HierarchyPolicy::Platform,
+            TpmHandle::Permanent(PermanentTpmHandle::Platform),
+            ObjectHandle::Platform,
+            "PLATFORM",
+        );
+        test_conversion(
+            HierarchyPolicy::Endorsement,
+            TpmHandle::Permanent(PermanentTpmHandle::Endorsement),
+            ObjectHandle::Endorsement,
+            "ENDORSEMENT",
+        );
+        test_conversion(
+            HierarchyPolicy::Lockout,
+            TpmHandle::Permanent(PermanentTpmHandle::Lockout),
+            ObjectHandle::Lockout,
+            "LOCKOUT",
+        );
+        #[cfg(has_esys_act)]
+        test_conversion(
+            HierarchyPolicy::Act(Act::Act0),
+            TpmHandle::Permanent(PermanentTpmHandle::AuthenticatedTimersFirst),
+            ObjectHandle::from(ESYS_TR_RH_ACT_FIRST),
+            "ACT_0",
+        );
+    }
+}
+
+#[cfg(has_esys_act)]
+mod test_act {
+    use super::*;
+    #[test]
+    fn test_conversions() {
+        for number in 0u8..16 {
+            let act = Act::from_number(number).expect("Failed to create Act from number");
+            assert_eq!(act.number(), number);
+
+            let esys_rh = ObjectHandle::from(ESYS_TR_RH_ACT_FIRST + u32::from(number));
+            let tpm_rh = TpmHandle::Permanent(
+                PermanentTpmHandle::new(TPM2_RH_ACT_0 + u32::from(number))
+                    .expect("Failed to create permanent TPM handle"),
+            );
+            assert_eq!(ObjectHandle::from(act), esys_rh);
+            assert_eq!(AuthHandle::from(act), AuthHandle::from(esys_rh));
+            assert_eq!(TpmHandle::from(act), tpm_rh);
+            assert_eq!(
+                Act::try_from(esys_rh).expect("Failed to convert ObjectHandle into Act"),
+                act
+            );
+            assert_eq!(
+                Act::try_from(AuthHandle::from(esys_rh))
+                    .expect("Failed to convert AuthHandle into Act"),
+                act
+            );
+            assert_eq!(
+                Act::try_from(tpm_rh).expect("Failed to convert TpmHandle into Act"),
+                act
+            );
+        }
+
+        assert_eq!(
+            TpmHandle::from(Act::ActF),
+            TpmHandle::Permanent(PermanentTpmHandle::AuthenticatedTimersLast)
+        );
+        assert_eq!(
+            ObjectHandle::from(Act::ActF),
+            ObjectHandle::from(ESYS_TR_RH_ACT_LAST)
+        );
+    }
+
+    #[test]
+    fn test_invalid_conversions() {
+        let _ = Act::from_number(16).unwrap_err();
+        let _ = Act::try_from(ObjectHandle::Owner).unwrap_err();
+        let _ = Act::try_from(ObjectHandle::from(ESYS_TR_RH_ACT_LAST + 1)).unwrap_err();
+        let _ = Act::try_from(TpmHandle::Permanent(PermanentTpmHandle::Owner)).unwrap_err();
+    }
+}
+
 mod test_platform {
     use super::*;
     #[test]
@@ -14,5 +14,6 @@ mod handles_tests;
 mod interface_types_tests;
 mod structures_tests;
 mod tcti_ldr_tests;
+mod tcti_tests;
 mod traits;
 mod utils_tests;
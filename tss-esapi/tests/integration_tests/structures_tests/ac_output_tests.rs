@@ -0,0 +1,60 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{AcCapability, VendorAcCapability},
+    structures::AcOutput,
+    tss2_esys::TPMS_AC_OUTPUT,
+};
+
+use std::convert::{TryFrom, TryInto};
+
+#[test]
+fn test_conversions() {
+    let expected_tag = AcCapability::Pv1;
+    let expected_data = 0x1234_5678u32;
+
+    let expected_tpms_ac_output = TPMS_AC_OUTPUT {
+        tag: expected_tag.into(),
+        data: expected_data,
+    };
+
+    let ac_output: AcOutput = expected_tpms_ac_output
+        .try_into()
+        .expect("Failed to convert TPMS_AC_OUTPUT");
+
+    assert_eq!(
+        ac_output.tag(),
+        expected_tag,
+        "Converted AcOutput did not contain the expected tag value"
+    );
+
+    assert_eq!(
+        ac_output.data(),
+        expected_data,
+        "Converted AcOutput did not contain the expected data value",
+    );
+
+    let actual_tpms_ac_output: TPMS_AC_OUTPUT = ac_output.into();
+
+    crate::common::ensure_tpms_ac_output_equality(&expected_tpms_ac_output, &actual_tpms_ac_output);
+}
+
+#[test]
+fn test_vendor_tag_conversion() {
+    let vendor_tag = 0x8000_0005;
+    let ac_output = AcOutput::try_from(TPMS_AC_OUTPUT {
+        tag: vendor_tag,
+        data: 1,
+    })
+    .expect("Failed to convert TPMS_AC_OUTPUT with vendor specific tag");
+    assert_eq!(
+        ac_output.tag(),
+        AcCapability::Vendor(VendorAcCapability::try_from(vendor_tag).unwrap())
+    );
+    assert_eq!(TPMS_AC_OUTPUT::from(ac_output).tag, vendor_tag);
+}
+
+#[test]
+fn test_invalid_conversion() {
+    let _ = AcOutput::try_from(TPMS_AC_OUTPUT { tag: 3, data: 0 }).unwrap_err();
+}
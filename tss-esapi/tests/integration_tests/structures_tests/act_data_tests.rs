@@ -0,0 +1,57 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    attributes::ActAttributes, constants::tss::TPM2_RH_ACT_0,
+    interface_types::reserved_handles::Act, structures::ActData, tss2_esys::TPMS_ACT_DATA,
+};
+
+use std::convert::{TryFrom, TryInto};
+
+#[test]
+fn test_conversions() {
+    let expected_act = Act::Act2;
+    let expected_timeout = 30u32;
+    let expected_attributes = ActAttributes::from(0x1);
+
+    let expected_tpms_act_data = TPMS_ACT_DATA {
+        handle: TPM2_RH_ACT_0 + 2,
+        timeout: expected_timeout,
+        attributes: expected_attributes.into(),
+    };
+
+    let act_data: ActData = expected_tpms_act_data
+        .try_into()
+        .expect("Failed to convert TPMS_ACT_DATA");
+
+    assert_eq!(
+        act_data.act(),
+        expected_act,
+        "Converted ActData did not contain the expected act value"
+    );
+
+    assert_eq!(
+        act_data.timeout(),
+        expected_timeout,
+        "Converted ActData did not contain the expected timeout value",
+    );
+
+    assert_eq!(
+        act_data.attributes(),
+        expected_attributes,
+        "Converted ActData did not contain the expected attributes value",
+    );
+
+    let actual_tpms_act_data: TPMS_ACT_DATA = act_data.into();
+
+    crate::common::ensure_tpms_act_data_equality(&expected_tpms_act_data, &actual_tpms_act_data);
+}
+
+#[test]
+fn test_invalid_conversion() {
+    let _ = ActData::try_from(TPMS_ACT_DATA {
+        handle: TPM2_RH_ACT_0 - 1,
+        timeout: 0,
+        attributes: 0,
+    })
+    .unwrap_err();
+}
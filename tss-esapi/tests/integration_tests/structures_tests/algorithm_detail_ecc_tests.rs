@@ -0,0 +1,75 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::convert::TryFrom;
+use tss_esapi::{
+    interface_types::{algorithm::HashingAlgorithm, ecc::EccCurve},
+    structures::{
+        AlgorithmDetailEcc, EccParameter, EccPoint, EccScheme, HashScheme,
+        KeyDerivationFunctionScheme,
+    },
+    tss2_esys::TPMS_ALGORITHM_DETAIL_ECC,
+};
+
+#[test]
+fn test_conversion() {
+    let expected_p = EccParameter::try_from(vec![0xffu8; 32]).unwrap();
+    let expected_a = EccParameter::try_from(vec![0xfcu8; 32]).unwrap();
+    let expected_b = EccParameter::try_from(vec![0x5au8; 32]).unwrap();
+    let expected_g_x = EccParameter::try_from(vec![0x6bu8; 32]).unwrap();
+    let expected_g_y = EccParameter::try_from(vec![0x4fu8; 32]).unwrap();
+    let expected_n = EccParameter::try_from(vec![0x51u8; 32]).unwrap();
+    let expected_h = EccParameter::try_from(vec![0x01u8]).unwrap();
+    let expected_ecc_scheme = EccScheme::EcDsa(HashScheme::new(HashingAlgorithm::Sha256));
+    let expected_tpms_algorithm_detail_ecc = TPMS_ALGORITHM_DETAIL_ECC {
+        curveID: EccCurve::NistP256.into(),
+        keySize: 256,
+        kdf: KeyDerivationFunctionScheme::Null.into(),
+        sign: expected_ecc_scheme.into(),
+        p: expected_p.clone().into(),
+        a: expected_a.clone().into(),
+        b: expected_b.clone().into(),
+        gX: expected_g_x.clone().into(),
+        gY: expected_g_y.clone().into(),
+        n: expected_n.clone().into(),
+        h: expected_h.clone().into(),
+    };
+
+    let algorithm_detail_ecc = AlgorithmDetailEcc::try_from(expected_tpms_algorithm_detail_ecc)
+        .expect("Failed to convert TPMS_ALGORITHM_DETAIL_ECC into AlgorithmDetailEcc");
+
+    assert_eq!(EccCurve::NistP256, algorithm_detail_ecc.curve());
+    assert_eq!(256, algorithm_detail_ecc.key_size());
+    assert_eq!(
+        KeyDerivationFunctionScheme::Null,
+        algorithm_detail_ecc.key_derivation_function_scheme()
+    );
+    assert_eq!(expected_ecc_scheme, algorithm_detail_ecc.ecc_scheme());
+    assert_eq!(&expected_p, algorithm_detail_ecc.p());
+    assert_eq!(&expected_a, algorithm_detail_ecc.a());
+    assert_eq!(&expected_b, algorithm_detail_ecc.b());
+    assert_eq!(&expected_g_x, algorithm_detail_ecc.g_x());
+    assert_eq!(&expected_g_y, algorithm_detail_ecc.g_y());
+    assert_eq!(
+        EccPoint::new(expected_g_x, expected_g_y),
+        algorithm_detail_ecc.g()
+    );
+    assert_eq!(&expected_n, algorithm_detail_ecc.n());
+    assert_eq!(&expected_h, algorithm_detail_ecc.h());
+
+    let actual_tpms_algorithm_detail_ecc: TPMS_ALGORITHM_DETAIL_ECC = algorithm_detail_ecc.into();
+
+    crate::common::ensure_tpms_algorithm_detail_ecc_equality(
+        &expected_tpms_algorithm_detail_ecc,
+        &actual_tpms_algorithm_detail_ecc,
+    );
+}
+
+#[test]
+fn test_invalid_curve_conversion() {
+    let tpms_algorithm_detail_ecc = TPMS_ALGORITHM_DETAIL_ECC {
+        curveID: 0xffff,
+        ..Default::default()
+    };
+    assert!(AlgorithmDetailEcc::try_from(tpms_algorithm_detail_ecc).is_err());
+}
@@ -10,3 +10,4 @@ mod private;
 mod public;
 mod sensitive;
 mod sensitive_create_buffer_tests;
+mod template;
@@ -0,0 +1,113 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::convert::TryFrom;
+use tss_esapi::{
+    structures::{Derive, Public, TemplateBuffer},
+    traits::{Marshall, UnMarshall},
+    tss2_esys::TPM2B_TEMPLATE,
+    Error, WrapperErrorKind,
+};
+
+const TEMPLATE_BUFFER_MAX_SIZE: usize = 612;
+
+#[test]
+fn test_max_sized_data() {
+    let _ = TemplateBuffer::try_from(vec![0xffu8; TEMPLATE_BUFFER_MAX_SIZE])
+        .expect("Failed to parse buffer of maximum size as TemplateBuffer");
+}
+
+#[test]
+fn test_to_large_data() {
+    assert_eq!(
+        TemplateBuffer::try_from(vec![0xffu8; TEMPLATE_BUFFER_MAX_SIZE + 1])
+            .expect_err("Converting a buffer that is to large did not produce an error"),
+        Error::WrapperError(WrapperErrorKind::WrongParamSize),
+        "Wrong kind of error when converting a buffer with size {} to TemplateBuffer",
+        TEMPLATE_BUFFER_MAX_SIZE + 1
+    );
+}
+
+#[test]
+fn test_to_large_derive_label() {
+    assert_eq!(
+        Derive::new(vec![0xffu8; Derive::MAX_LABEL_SIZE + 1], Vec::new())
+            .expect_err("Creating a Derive with a label that is to large did not produce an error"),
+        Error::WrapperError(WrapperErrorKind::WrongParamSize),
+    );
+    assert_eq!(
+        Derive::new(Vec::new(), vec![0xffu8; Derive::MAX_LABEL_SIZE + 1]).expect_err(
+            "Creating a Derive with a context that is to large did not produce an error"
+        ),
+        Error::WrapperError(WrapperErrorKind::WrongParamSize),
+    );
+}
+
+#[test]
+fn test_tss_conversion() {
+    crate::common::publics().iter().for_each(|public| {
+        let template = TemplateBuffer::try_from(public.clone())
+            .expect("Failed to convert from Public to TemplateBuffer");
+        assert_eq!(
+            public,
+            &Public::unmarshall(&template).expect("Failed to unmarshall template")
+        );
+        let tss_template = TPM2B_TEMPLATE::from(template.clone());
+        assert_eq!(
+            template,
+            TemplateBuffer::try_from(tss_template)
+                .expect("Failed to convert from TPM2B_TEMPLATE to TemplateBuffer")
+        );
+    });
+}
+
+#[test]
+fn test_with_derive() {
+    let derive = Derive::new(vec![1, 2, 3], vec![4, 5]).expect("Failed to create Derive");
+    let expected_derive = vec![0x00, 0x03, 1, 2, 3, 0x00, 0x02, 4, 5];
+    assert_eq!(
+        expected_derive,
+        derive.marshall().expect("Failed to marshall Derive")
+    );
+
+    crate::common::publics().iter().for_each(|public| {
+        let unique_size = match public {
+            Public::Rsa { unique, .. } => 2 + unique.len(),
+            Public::KeyedHash { unique, .. } | Public::SymCipher { unique, .. } => 2 + unique.len(),
+            Public::Ecc { unique, .. } => 4 + unique.x().len() + unique.y().len(),
+        };
+        let marshalled_public = public.marshall().expect("Failed to marshall Public");
+        let template = TemplateBuffer::with_derive(public.clone(), derive.clone())
+            .expect("Failed to create TemplateBuffer with Derive");
+        assert_eq!(
+            [
+                &marshalled_public[..marshalled_public.len() - unique_size],
+                &expected_derive[..]
+            ]
+            .concat(),
+            template.value()
+        );
+    });
+}
+
+#[cfg(has_tpms_derive)]
+#[test]
+fn test_derive_tss_conversion() {
+    use tss_esapi::tss2_esys::TPMS_DERIVE;
+
+    let derive = Derive::new(vec![1, 2, 3], vec![4, 5]).expect("Failed to create Derive");
+    let tpms_derive = TPMS_DERIVE::from(derive.clone());
+    assert_eq!(3, tpms_derive.label.size);
+    assert_eq!([1, 2, 3], tpms_derive.label.buffer[..3]);
+    assert_eq!(2, tpms_derive.context.size);
+    assert_eq!([4, 5], tpms_derive.context.buffer[..2]);
+    assert_eq!(
+        derive,
+        Derive::try_from(tpms_derive).expect("Failed to convert from TPMS_DERIVE to Derive")
+    );
+    assert_eq!(
+        derive,
+        Derive::unmarshall(&derive.marshall().expect("Failed to marshall Derive"))
+            .expect("Failed to unmarshall Derive")
+    );
+}
@@ -158,17 +158,44 @@ fn test_ecc_curves() {
 //     }
 // }
 
-// For these tests to work the tpm2-tss library need to have the
-// actData field in the TPMU_CAPABILITIES union.
-// #[test]
-// fn test_act() {
-//     let mut context = create_ctx_without_session();
+#[cfg(has_esys_act)]
+#[test]
+fn test_act() {
+    use std::convert::TryFrom;
+    use tss_esapi::{constants::tss::TPM2_RH_ACT_0, interface_types::reserved_handles::Act};
 
-//     let (capabs, _more) = context.get_capability(CapabilityType::Act, 0, 80).unwrap();
+    let mut context = create_ctx_without_session();
 
-//     if let CapabilityData::ActData(list) = capabs {
-//         assert!(!list.is_empty());
-//     } else {
-//         panic!("Got wrong type of capability data: {:?}", capabs);
-//     }
-// }
+    let (capabs, _more) = context
+        .get_capability(CapabilityType::Act, TPM2_RH_ACT_0, 16)
+        .unwrap();
+    let list = if let CapabilityData::ActData(list) = capabs {
+        list
+    } else {
+        panic!("Got wrong type of capability data: {:?}", capabs);
+    };
+
+    let (capabs, _more) = context
+        .get_capability(CapabilityType::Handles, TPM2_RH_ACT_0, 16)
+        .unwrap();
+    let handles = if let CapabilityData::Handles(handles) = capabs {
+        handles
+    } else {
+        panic!("Got wrong type of capability data: {:?}", capabs);
+    };
+
+    // Authenticated countdown timers are optional, so the list is empty
+    // if the TPM does not implement any. The ACT data needs to match the
+    // ACT handles that are reported among the permanent handles.
+    let implemented_acts: Vec<Act> = handles
+        .into_inner()
+        .into_iter()
+        .filter_map(|handle| Act::try_from(handle).ok())
+        .collect();
+    assert_eq!(
+        implemented_acts,
+        list.iter()
+            .map(|act_data| act_data.act())
+            .collect::<Vec<Act>>()
+    );
+}
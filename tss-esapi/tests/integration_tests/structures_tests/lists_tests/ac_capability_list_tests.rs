@@ -0,0 +1,87 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::{TryFrom, TryInto};
+use tss_esapi::{
+    constants::AcCapability,
+    structures::{AcCapabilityList, AcOutput},
+    tss2_esys::{TPML_AC_CAPABILITIES, TPMS_AC_OUTPUT},
+    Error, WrapperErrorKind,
+};
+
+#[test]
+fn test_valid_conversions() {
+    let expected_ac_outputs: Vec<AcOutput> = vec![
+        AcOutput::new(AcCapability::Any, 0u32),
+        AcOutput::new(AcCapability::Error, 1u32),
+        AcOutput::new(AcCapability::Pv1, 0x1234_5678u32),
+    ];
+
+    let expected_tpml_ac_capabilities: TPML_AC_CAPABILITIES =
+        expected_ac_outputs
+            .iter()
+            .fold(Default::default(), |mut acc, v| {
+                acc.acCapabilities[acc.count as usize] = TPMS_AC_OUTPUT::from(*v);
+                acc.count += 1;
+                acc
+            });
+
+    let ac_capability_list_from_vec: AcCapabilityList = expected_ac_outputs
+        .clone()
+        .try_into()
+        .expect("Failed to convert Vec<AcOutput> into AcCapabilityList");
+
+    assert_eq!(
+        expected_ac_outputs.len(),
+        ac_capability_list_from_vec.len(),
+        "Mismatch in 'len()' between the Vec<AcOutput> and the AcCapabilityList(from vec)"
+    );
+
+    expected_ac_outputs
+        .iter()
+        .zip(ac_capability_list_from_vec.as_ref())
+        .for_each(|(expected, actual)| {
+            assert_eq!(expected, actual, "Mismatch between an expected AcOutput in the Vec<AcOutput> the actual AcOutput in AcCapabilityList(from vec)");
+        });
+
+    let ac_capability_list_from_tss: AcCapabilityList = expected_tpml_ac_capabilities
+        .try_into()
+        .expect("Failed to convert TPML_AC_CAPABILITIES into AcCapabilityList");
+
+    assert_eq!(
+        expected_ac_outputs.len(),
+        ac_capability_list_from_tss.len(),
+        "Mismatch in 'len()' between the Vec<AcOutput> and the AcCapabilityList(from tss)"
+    );
+
+    expected_ac_outputs
+        .iter()
+        .zip(ac_capability_list_from_tss.as_ref())
+        .for_each(|(expected, actual)| {
+            assert_eq!(expected, actual, "Mismatch between an expected AcOutput in the Vec<AcOutput> the actual AcOutput in AcCapabilityList(from tss)");
+        });
+
+    let actual_tpml_ac_capabilities: TPML_AC_CAPABILITIES = ac_capability_list_from_vec.into();
+
+    crate::common::ensure_tpml_ac_capabilities_equality(
+        &expected_tpml_ac_capabilities,
+        &actual_tpml_ac_capabilities,
+    );
+}
+
+#[test]
+fn test_invalid_conversions() {
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+        AcCapabilityList::try_from(vec![AcOutput::new(AcCapability::Any, 0u32); AcCapabilityList::MAX_SIZE + 1]),
+        "Converting a vector with to many elements into an AcCapabilityList did not produce the expected error",
+    );
+
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+        AcCapabilityList::try_from(TPML_AC_CAPABILITIES {
+            count: AcCapabilityList::MAX_SIZE as u32 + 1u32,
+            acCapabilities: [Default::default(); 128],
+        }),
+        "Converting a TPML_AC_CAPABILITIES with an invalid 'count' value into an AcCapabilityList did not produce the expected error",
+    );
+}
@@ -0,0 +1,108 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::{TryFrom, TryInto};
+use tss_esapi::{
+    attributes::ActAttributes,
+    interface_types::reserved_handles::Act,
+    structures::{ActData, ActDataList},
+    tss2_esys::{TPML_ACT_DATA, TPMS_ACT_DATA},
+    Error, WrapperErrorKind,
+};
+
+#[test]
+fn test_valid_conversions() {
+    let expected_act_data: Vec<ActData> = vec![
+        ActData::new(Act::Act0, 0u32, ActAttributes::from(0)),
+        ActData::new(Act::Act1, 10u32, ActAttributes::from(1)),
+        ActData::new(Act::ActF, 0x1234_5678u32, ActAttributes::from(2)),
+    ];
+
+    let expected_tpml_act_data: TPML_ACT_DATA =
+        expected_act_data
+            .iter()
+            .fold(Default::default(), |mut acc, v| {
+                acc.actData[acc.count as usize] = TPMS_ACT_DATA::from(*v);
+                acc.count += 1;
+                acc
+            });
+
+    let act_data_list_from_vec: ActDataList = expected_act_data
+        .clone()
+        .try_into()
+        .expect("Failed to convert Vec<ActData> into ActDataList");
+
+    assert_eq!(
+        expected_act_data.len(),
+        act_data_list_from_vec.len(),
+        "Mismatch in 'len()' between the Vec<ActData> and the ActDataList(from vec)"
+    );
+
+    expected_act_data
+        .iter()
+        .zip(act_data_list_from_vec.as_ref())
+        .for_each(|(expected, actual)| {
+            assert_eq!(expected, actual, "Mismatch between an expected ActData in the Vec<ActData> the actual ActData in ActDataList(from vec)");
+        });
+
+    let act_data_list_from_tss: ActDataList = expected_tpml_act_data
+        .try_into()
+        .expect("Failed to convert TPML_ACT_DATA into ActDataList");
+
+    assert_eq!(
+        expected_act_data.len(),
+        act_data_list_from_tss.len(),
+        "Mismatch in 'len()' between the Vec<ActData> and the ActDataList(from tss)"
+    );
+
+    expected_act_data
+        .iter()
+        .zip(act_data_list_from_tss.as_ref())
+        .for_each(|(expected, actual)| {
+            assert_eq!(expected, actual, "Mismatch between an expected ActData in the Vec<ActData> the actual ActData in ActDataList(from tss)");
+        });
+
+    let actual_tpml_act_data: TPML_ACT_DATA = act_data_list_from_vec.into();
+
+    crate::common::ensure_tpml_act_data_equality(&expected_tpml_act_data, &actual_tpml_act_data);
+}
+
+#[test]
+fn test_invalid_conversions() {
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+        ActDataList::try_from(vec![ActData::new(Act::Act0, 0u32, ActAttributes::from(0)); ActDataList::MAX_SIZE + 1]),
+        "Converting a vector with to many elements into an ActDataList did not produce the expected error",
+    );
+
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+        ActDataList::try_from(TPML_ACT_DATA {
+            count: ActDataList::MAX_SIZE as u32 + 1u32,
+            actData: [Default::default(); 84],
+        }),
+        "Converting a TPML_ACT_DATA with an invalid 'count' value into an ActDataList did not produce the expected error",
+    );
+}
+
+#[test]
+fn test_find() {
+    let act_data_list: ActDataList = vec![
+        ActData::new(Act::Act0, 0u32, ActAttributes::from(0)),
+        ActData::new(Act::Act1, 10u32, ActAttributes::from(1)),
+    ]
+    .try_into()
+    .expect("Failed to convert Vec<ActData> into ActDataList");
+
+    assert_eq!(
+        &ActData::new(Act::Act1, 10u32, ActAttributes::from(1)),
+        act_data_list
+            .find(Act::Act1)
+            .expect("Calling find with Act::Act1 returned an unexpected 'None'"),
+        "'find(Act::Act1)' did not return the expected ActData value",
+    );
+
+    assert!(
+        act_data_list.find(Act::ActF).is_none(),
+        "A value that should not exist was found in the ActDataList"
+    );
+}
@@ -0,0 +1,171 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use tss_esapi::{
+    constants::AlgorithmIdentifier,
+    structures::AlgorithmList,
+    tss2_esys::{TPM2_ALG_ID, TPML_ALG},
+    Error, WrapperErrorKind,
+};
+
+use std::convert::{TryFrom, TryInto};
+
+#[test]
+fn test_conversions() {
+    let expected_algorithms = [
+        AlgorithmIdentifier::Rsa,
+        AlgorithmIdentifier::Sha256,
+        AlgorithmIdentifier::Aes,
+    ];
+    let mut algorithm_list = AlgorithmList::new();
+    for algorithm in expected_algorithms.iter() {
+        algorithm_list
+            .add(*algorithm)
+            .expect("Failed to add algorithm identifier to algorithm list");
+    }
+
+    assert_eq!(
+        expected_algorithms.len(),
+        algorithm_list.len(),
+        "The created algorithm list did not contain the expected number of elements"
+    );
+
+    expected_algorithms
+        .iter()
+        .zip(algorithm_list.as_ref().iter())
+        .for_each(|(expected, actual)| {
+            assert_eq!(
+                expected, actual,
+                "The created algorithm list did not contain the expected value"
+            )
+        });
+
+    let tpml_alg: TPML_ALG = algorithm_list.into();
+
+    assert_eq!(
+        expected_algorithms.len(),
+        tpml_alg.count as usize,
+        "The number count field in TPML_ALG did not contain the expected value"
+    );
+
+    expected_algorithms
+        .iter()
+        .zip(tpml_alg.algorithms[0..tpml_alg.count as usize].iter())
+        .for_each(|(expected, actual)| {
+            assert_eq!(
+                TPM2_ALG_ID::from(*expected),
+                *actual,
+                "Algorithm identifier mismatch between algorithm identifiers in AlgorithmList and converted AlgorithmList"
+            )
+        });
+
+    let converted_algorithm_list: AlgorithmList = tpml_alg
+        .try_into()
+        .expect("Failed to convert TPML_ALG to AlgorithmList");
+
+    assert_eq!(
+        expected_algorithms.len(),
+        converted_algorithm_list.len(),
+        "The algorithm list converted from TPML_ALG did not contain the expected number of elements"
+    );
+
+    expected_algorithms
+        .iter()
+        .zip(converted_algorithm_list.as_ref().iter())
+        .for_each(|(expected, actual)| {
+            assert_eq!(
+                expected, actual, "Algorithm identifier in algorithm list converted from TPML_ALG did not match the expected value"
+            )
+        });
+}
+
+#[test]
+fn test_valid_conversion_vector() {
+    let expected_algorithms = [
+        AlgorithmIdentifier::Rsa,
+        AlgorithmIdentifier::Sha256,
+        AlgorithmIdentifier::Aes,
+    ];
+    let mut algorithm_list = AlgorithmList::new();
+    for algorithm in expected_algorithms.iter() {
+        algorithm_list
+            .add(*algorithm)
+            .expect("Failed to add algorithm identifier to algorithm list");
+    }
+
+    assert_eq!(
+        expected_algorithms.len(),
+        algorithm_list.len(),
+        "The created algorithm list did not contain the expected number of elements"
+    );
+
+    expected_algorithms
+        .iter()
+        .zip(algorithm_list.as_ref().iter())
+        .for_each(|(expected, actual)| {
+            assert_eq!(
+                expected, actual,
+                "The created algorithm list did not contain the expected value"
+            )
+        });
+
+    let actual_algorithms: Vec<AlgorithmIdentifier> = algorithm_list.into();
+
+    assert_eq!(
+        expected_algorithms.len(),
+        actual_algorithms.len(),
+        "The Vec<AlgorithmIdentifier> converted from AlgorithmList did not contain the expected number of elements"
+    );
+
+    expected_algorithms
+        .iter()
+        .zip(actual_algorithms.iter())
+        .for_each(|(expected, actual)| {
+            assert_eq!(
+                expected, actual, "Algorithm identifier in algorithm list converted from TPML_ALG did not match the expected value"
+            )
+        });
+}
+
+#[test]
+fn test_invalid_conversions() {
+    let mut algorithm_list = AlgorithmList::new();
+    for _ in 0..AlgorithmList::MAX_SIZE {
+        algorithm_list
+            .add(AlgorithmIdentifier::Rsa)
+            .expect("Failed to algorithm identifier to list");
+    }
+
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::WrongParamSize)),
+        algorithm_list.add(AlgorithmIdentifier::Rsa),
+        "Adding more algorithm identifiers to algorithm list then it supports did not produce the expected error"
+    );
+}
+
+#[test]
+fn test_invalid_conversion_from_tpml_alg() {
+    let invalid_value = TPML_ALG {
+        count: AlgorithmList::MAX_SIZE as u32 + 1u32,
+        algorithms: [0; 128],
+    };
+
+    assert_eq!(
+        Error::WrapperError(WrapperErrorKind::InvalidParam),
+        AlgorithmList::try_from(invalid_value).expect_err(
+            "Converting a TPML_ALG with invalid values to AlgorithmList did not produce an error"
+        ),
+        "Converting invalid TPML_ALG did not produce the expected error",
+    );
+}
+
+#[test]
+fn test_invalid_conversions_from_vector() {
+    assert_eq!(
+        Error::WrapperError(WrapperErrorKind::InvalidParam),
+        AlgorithmList::try_from(vec![AlgorithmIdentifier::Rsa; AlgorithmList::MAX_SIZE + 1]).expect_err(
+            "Converting Vec<AlgorithmIdentifier> of invalid length to AlgorithmList did not produce an error"
+        ),
+        "Converting invalid Vec<AlgorithmIdentifier> did not produce the expected error",
+    );
+}
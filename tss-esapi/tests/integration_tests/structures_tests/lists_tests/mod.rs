@@ -1,5 +1,9 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod ac_capability_list_tests;
+#[cfg(has_esys_act)]
+mod act_data_list_tests;
+mod algorithm_list_tests;
 mod algorithm_property_list_tests;
 mod command_code_attributes_list_tests;
 mod command_code_list_tests;
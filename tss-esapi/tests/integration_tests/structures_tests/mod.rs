@@ -1,5 +1,9 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod ac_output_tests;
+#[cfg(has_esys_act)]
+mod act_data_tests;
+mod algorithm_detail_ecc_tests;
 mod algorithm_property_tests;
 mod algorithm_tests;
 mod attest_info_test;
@@ -12,6 +16,8 @@ mod command_audit_info_tests;
 mod creation_info_tests;
 mod lists_tests;
 mod nv_certify_info_tests;
+#[cfg(has_tpmu_attest_nv_digest)]
+mod nv_digest_certify_info_tests;
 mod pcr_tests;
 mod quote_info_tests;
 mod session_audit_info_tests;
@@ -0,0 +1,80 @@
+// Copyright 2024 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tss_esapi::{tcti::Tcti, Result};
+
+/// TCTI that records the commands and answers them with a fixed response.
+struct RecordingTcti {
+    commands: Arc<Mutex<Vec<Vec<u8>>>>,
+    response: Vec<u8>,
+}
+
+impl Tcti for RecordingTcti {
+    fn transmit(&mut self, command: &[u8]) -> Result<()> {
+        self.commands.lock().unwrap().push(command.to_vec());
+        Ok(())
+    }
+
+    fn receive(&mut self, _timeout: Option<Duration>) -> Result<Option<Vec<u8>>> {
+        Ok(Some(self.response.clone()))
+    }
+}
+
+mod test_new_with_tcti {
+    use super::*;
+    use tss_esapi::Context;
+
+    #[test]
+    fn test_get_random() {
+        let commands = Arc::new(Mutex::new(Vec::new()));
+        let tcti = RecordingTcti {
+            commands: commands.clone(),
+            // TPM2_ST_NO_SESSIONS, size, TPM_RC_SUCCESS and a TPM2B_DIGEST.
+            response: vec![
+                0x80, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xDE, 0xAD,
+                0xBE, 0xEF,
+            ],
+        };
+        let mut context = Context::new_with_tcti(tcti).expect("Failed to create context");
+
+        let random = context.get_random(4).expect("Call to get_random failed");
+        assert_eq!(&[0xDE, 0xAD, 0xBE, 0xEF], random.as_bytes());
+
+        // TPM2_ST_NO_SESSIONS, size, TPM2_CC_GetRandom and the number of bytes.
+        assert_eq!(
+            vec![vec![
+                0x80, 0x01, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x00, 0x01, 0x7B, 0x00, 0x04
+            ]],
+            *commands.lock().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_tcti_error() {
+        struct FailingTcti;
+
+        impl Tcti for FailingTcti {
+            fn transmit(&mut self, _command: &[u8]) -> Result<()> {
+                Err(tss_esapi::Error::WrapperError(
+                    tss_esapi::WrapperErrorKind::InternalError,
+                ))
+            }
+
+            fn receive(&mut self, _timeout: Option<Duration>) -> Result<Option<Vec<u8>>> {
+                Ok(None)
+            }
+        }
+
+        let mut context = Context::new_with_tcti(FailingTcti).expect("Failed to create context");
+        let _ = context
+            .get_random(4)
+            .expect_err("Call to get_random should have failed");
+    }
+
+    #[test]
+    fn test_context_is_send_and_sync() {
+        fn assert_send_and_sync<T: Send + Sync>() {}
+        assert_send_and_sync::<Context>();
+    }
+}